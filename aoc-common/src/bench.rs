@@ -0,0 +1,88 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{ParseError, Solution};
+
+/// Summary of repeated timing measurements
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            samples: n,
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+            max: sorted[n - 1],
+        }
+    }
+}
+
+/// Timings of the separate stages of a solution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Runs parsing and both parts of `S` on `input` `iterations` times, timing each stage separately
+pub fn measure<S: Solution>(input: &str, iterations: usize) -> Result<Timings, ParseError> {
+    let iterations = iterations.max(1);
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        part2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
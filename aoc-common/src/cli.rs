@@ -0,0 +1,130 @@
+use std::{fmt::Display, path::PathBuf, process::ExitCode, time::Instant};
+
+use clap::{Args, Parser};
+
+use crate::{
+    input::InputSource,
+    logging::Verbosity,
+    output::{self, Format},
+    solve_parts,
+    stream::{stream_parts, StreamingSolution},
+    Answer, Part, Solution,
+};
+
+/// Arguments shared by every day's binary. Days with extra options flatten this into their own parser
+#[derive(Args)]
+pub struct DayArgs {
+    /// Input file, '-' for stdin. Falls back to $AOC_INPUT, then the day's default input
+    input: Option<PathBuf>,
+
+    /// Output format. Diagnostics always go to stderr
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+impl DayArgs {
+    /// Sets up logging and resolves where to read the input from
+    pub fn init(&self, default_input: &str) -> InputSource {
+        self.verbosity.init_logger();
+
+        InputSource::resolve(self.input.clone(), default_input)
+    }
+}
+
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    args: DayArgs,
+}
+
+//The `--stream` flag of days that can be solved in a single pass, to flatten next to DayArgs.
+//A plain comment, since clap would show a doc comment as the description of the whole binary
+#[derive(Args)]
+pub struct StreamArgs {
+    /// Read the input line by line instead of loading all of it into memory
+    #[arg(long)]
+    pub stream: bool,
+}
+
+#[derive(Parser)]
+struct StreamingDayCli {
+    #[command(flatten)]
+    args: DayArgs,
+
+    #[command(flatten)]
+    streaming: StreamArgs,
+}
+
+/// Entry point shared by every day's binary
+pub fn main<S: Solution>(default_input: &str) -> ExitCode {
+    let DayCli { args } = DayCli::parse();
+
+    run_collected::<S>(&args, default_input)
+}
+
+/// The body of [`main`], for days that parse their own command line
+pub fn run_collected<S: Solution>(args: &DayArgs, default_input: &str) -> ExitCode {
+    let source = args.init(default_input);
+    run::<S>(args.format, &source, solve_collected::<S>)
+}
+
+/// Like [`main`], but with a `--stream` flag for days that can be solved in a single pass
+pub fn main_streaming<S: StreamingSolution>(default_input: &str) -> ExitCode {
+    let StreamingDayCli { args, streaming } = StreamingDayCli::parse();
+
+    run_streaming::<S>(&args, streaming.stream, default_input)
+}
+
+/// The body of [`main_streaming`], for days that parse their own command line
+pub fn run_streaming<S: StreamingSolution>(
+    args: &DayArgs,
+    stream: bool,
+    default_input: &str,
+) -> ExitCode {
+    let source = args.init(default_input);
+    run::<S>(args.format, &source, |source| {
+        if !stream {
+            return solve_collected::<S>(source);
+        }
+
+        let mut reader = source.open().map_err(|e| e.to_string())?;
+        stream_parts::<S>(&mut reader, &Part::BOTH).map_err(|e| e.with_file(source).to_string())
+    })
+}
+
+fn solve_collected<S: Solution>(source: &InputSource) -> Result<Vec<Answer>, String> {
+    let input = source.read().map_err(|e| e.to_string())?;
+
+    solve_parts::<S>(&input, &Part::BOTH).map_err(|e| e.with_file(source).to_string())
+}
+
+fn run<S: Solution>(
+    format: Format,
+    source: &InputSource,
+    solve: impl FnOnce(&InputSource) -> Result<Vec<Answer>, String>,
+) -> ExitCode {
+    exit_code(solve(source).map(|answers| output::print_answers(format, S::DAY, source, &answers)))
+}
+
+/// The answer to one part, for days that solve their own modes. Timed from `start`
+pub fn answer(part: Part, answer: impl Display, start: Instant) -> Answer {
+    Answer {
+        part,
+        answer: answer.to_string(),
+        duration: start.elapsed(),
+    }
+}
+
+/// Prints the error of a failed run to stderr
+pub fn exit_code(result: Result<(), impl Display>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::{error::Error, fmt::Display};
+
+/// An error in the puzzle input, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<String>,
+    message: String,
+    /// 1-based line number
+    line: usize,
+    /// 1-based column, counted in characters
+    column: usize,
+    /// Number of characters to underline
+    width: usize,
+    text: String,
+}
+
+impl ParseError {
+    /// Points at the whole line. `line_index` is 0-based, as returned by `lines().enumerate()`
+    pub fn at_line(line_index: usize, line: &str, message: impl Into<String>) -> Self {
+        Self::at_token(line_index, line, line, message)
+    }
+
+    /// Points at `token`, which has to be a substring slice of `line` (e.g. from `split`).
+    /// Falls back to the whole line if it is not
+    pub fn at_token(
+        line_index: usize,
+        line: &str,
+        token: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        let start = token.as_ptr() as usize;
+        let line_start = line.as_ptr() as usize;
+
+        let (offset, token) =
+            if start >= line_start && start + token.len() <= line_start + line.len() {
+                (start - line_start, token)
+            } else {
+                (0, line)
+            };
+
+        Self {
+            file: None,
+            message: message.into(),
+            line: line_index + 1,
+            column: line[..offset].chars().count() + 1,
+            width: token.chars().count().max(1),
+            text: line.to_owned(),
+        }
+    }
+
+    /// Points just past the last line of `input`, for input that ends too early
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        let line_index = input.lines().count();
+
+        Self {
+            column: 1,
+            ..Self::at_line(line_index, "", message)
+        }
+    }
+
+    /// Names the file the input came from, shown in the rendered error
+    pub fn with_file(mut self, file: impl Display) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{gutter}--> {file}:{}:{}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl Error for ParseError {}
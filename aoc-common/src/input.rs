@@ -0,0 +1,100 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+/// Environment variable consulted when no input is given on the command line
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+
+/// Where to read the puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Uses `arg` if given, then `$AOC_INPUT`, then `default`. A path of `-` means stdin
+    pub fn resolve(arg: Option<PathBuf>, default: impl Into<PathBuf>) -> Self {
+        let path = arg
+            .or_else(|| env::var_os(INPUT_ENV_VAR).map(PathBuf::from))
+            .unwrap_or_else(|| default.into());
+
+        Self::from_path(path)
+    }
+
+    pub fn from_path(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(path)
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf).map(|_| buf)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+
+    /// Opens the input for reading it line by line, without loading all of it into memory
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| InputError {
+                    source: self.clone(),
+                    error,
+                }),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            InputSource::Stdin => write!(f, "Could not read input from stdin: {}", self.error),
+            InputSource::File(path) => write!(
+                f,
+                "Could not read input file '{}': {} (pass a path, '-' for stdin or set {INPUT_ENV_VAR})",
+                path.display(),
+                self.error
+            ),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,76 @@
+pub mod bench;
+pub mod cli;
+mod error;
+pub mod input;
+pub mod logging;
+pub mod output;
+pub mod stream;
+pub mod testing;
+
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+pub use error::ParseError;
+
+/// A puzzle solution, split into parsing the input and solving both parts on the parsed input
+pub trait Solution {
+    /// The day of the advent calendar this puzzle was released on
+    const DAY: u8;
+
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed) -> Self::Answer1;
+
+    fn part2(parsed: &Self::Parsed) -> Self::Answer2;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+/// Solves one part of `S` and renders the answer
+pub fn solve<S: Solution>(parsed: &S::Parsed, part: Part) -> String {
+    match part {
+        Part::One => S::part1(parsed).to_string(),
+        Part::Two => S::part2(parsed).to_string(),
+    }
+}
+
+/// The rendered answer to one part, together with how long solving it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+/// Parses `input` and solves the requested parts of `S`
+pub fn solve_parts<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solve::<S>(&parsed, part);
+
+            Answer {
+                part,
+                answer,
+                duration: start.elapsed(),
+            }
+        })
+        .collect())
+}
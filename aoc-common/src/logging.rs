@@ -0,0 +1,38 @@
+use clap::{ArgAction, Args};
+use log::LevelFilter;
+
+/// `-v`/`-q` flags controlling how much diagnostic output goes to stderr
+#[derive(Debug, Clone, Copy, Default, Args)]
+pub struct Verbosity {
+    /// Log more details to stderr (-v: info, -vv: debug, -vvv: trace)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Don't log anything, not even warnings
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+}
+
+impl Verbosity {
+    pub fn level_filter(&self) -> LevelFilter {
+        if self.quiet {
+            return LevelFilter::Off;
+        }
+
+        match self.verbose {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Info,
+            2 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    }
+
+    /// Installs a logger writing to stderr at the selected level
+    pub fn init_logger(&self) {
+        env_logger::Builder::new()
+            .filter_level(self.level_filter())
+            .format_timestamp(None)
+            .format_target(false)
+            .init();
+    }
+}
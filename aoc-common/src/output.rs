@@ -0,0 +1,38 @@
+use clap::ValueEnum;
+use serde_json::json;
+
+use crate::{input::InputSource, Answer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable answers
+    #[default]
+    Text,
+    /// One JSON object per line, see `format_answer` for the fields
+    Json,
+}
+
+/// Renders one answer of `day`. In JSON mode this is a `{day, part, answer, input, duration}`
+/// record, with the duration in nanoseconds
+pub fn format_answer(format: Format, day: u8, input: &InputSource, answer: &Answer) -> String {
+    let part = answer.part as u8;
+
+    match format {
+        Format::Text => format!("Day {day} part {part}: {}", answer.answer),
+        Format::Json => json!({
+            "day": day,
+            "part": part,
+            "answer": answer.answer,
+            "input": input.to_string(),
+            "duration": answer.duration.as_nanos() as u64,
+        })
+        .to_string(),
+    }
+}
+
+/// Prints the answers of `day` to stdout, one per line
+pub fn print_answers(format: Format, day: u8, input: &InputSource, answers: &[Answer]) {
+    for answer in answers {
+        println!("{}", format_answer(format, day, input, answer));
+    }
+}
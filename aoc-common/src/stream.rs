@@ -0,0 +1,123 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+    time::Instant,
+};
+
+use crate::{Answer, ParseError, Part, Solution};
+
+/// A solution that can solve both parts in a single pass over the input, one line at a time.
+/// Only `State` is kept between lines, so memory stays bounded no matter how large the input is
+pub trait StreamingSolution: Solution {
+    /// Everything that needs to be remembered between lines
+    type State: Default;
+
+    /// Processes one line. `index` is 0-based, like `lines().enumerate()`
+    fn feed(state: &mut Self::State, index: usize, line: &str) -> Result<(), ParseError>;
+
+    /// Called after the last line to get the answers to both parts. Errors found only at the
+    /// end of the input point just past its last line
+    fn finish(state: Self::State) -> Result<(Self::Answer1, Self::Answer2), ParseError>;
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl StreamError {
+    /// Names the file the input came from, see [`ParseError::with_file`]
+    pub fn with_file(self, file: impl Display) -> Self {
+        match self {
+            StreamError::Parse(e) => StreamError::Parse(e.with_file(file)),
+            e => e,
+        }
+    }
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "Could not read input: {e}"),
+            StreamError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(e) => Some(e),
+            StreamError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// Feeds every line of `reader` to `S`. Line endings are handled like `str::lines`,
+/// so the answers match the ones of the collected solution
+pub fn solve_stream<S: StreamingSolution>(
+    reader: impl BufRead,
+) -> Result<(S::Answer1, S::Answer2), StreamError> {
+    let mut state = S::State::default();
+    feed_stream::<S>(reader, &mut state)?;
+
+    Ok(S::finish(state)?)
+}
+
+/// Feeds every line of `reader` into an existing `state`, for days that need a custom initial state
+pub fn feed_stream<S: StreamingSolution>(
+    mut reader: impl BufRead,
+    state: &mut S::State,
+) -> Result<(), StreamError> {
+    //Reuse one buffer so memory does not grow with the input
+    let mut buf = String::new();
+    let mut index = 0;
+    while reader.read_line(&mut buf)? != 0 {
+        let line = buf.strip_suffix('\n').unwrap_or(&buf);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        S::feed(state, index, line)?;
+
+        index += 1;
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+/// Streams `reader` through `S` and renders the requested parts. Both parts are solved in the
+/// same pass, so each answer reports the duration of the whole pass
+pub fn stream_parts<S: StreamingSolution>(
+    reader: &mut dyn BufRead,
+    parts: &[Part],
+) -> Result<Vec<Answer>, StreamError> {
+    let start = Instant::now();
+    let (part1, part2) = solve_stream::<S>(reader)?;
+    let duration = start.elapsed();
+
+    Ok(parts
+        .iter()
+        .map(|&part| Answer {
+            part,
+            answer: match part {
+                Part::One => part1.to_string(),
+                Part::Two => part2.to_string(),
+            },
+            duration,
+        })
+        .collect())
+}
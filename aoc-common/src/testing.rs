@@ -0,0 +1,59 @@
+use std::fmt::Debug;
+
+use crate::{
+    stream::{solve_stream, StreamingSolution},
+    Solution,
+};
+
+/// Solves `input` with `S` and compares the answers against the expected ones.
+/// Parts with no expected answer are skipped, since some examples only cover one part
+pub fn check<S: Solution>(input: &str, part1: Option<S::Answer1>, part2: Option<S::Answer2>)
+where
+    S::Answer1: PartialEq + Debug,
+    S::Answer2: PartialEq + Debug,
+{
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("{e}"));
+
+    if let Some(expected) = part1 {
+        assert_eq!(S::part1(&parsed), expected, "part 1");
+    }
+    if let Some(expected) = part2 {
+        assert_eq!(S::part2(&parsed), expected, "part 2");
+    }
+}
+
+/// Streams `input` through `S` and checks that both answers match the collected solution
+pub fn check_stream<S: StreamingSolution>(input: &str)
+where
+    S::Answer1: PartialEq + Debug,
+    S::Answer2: PartialEq + Debug,
+{
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("{e}"));
+    let (part1, part2) = solve_stream::<S>(input.as_bytes()).unwrap_or_else(|e| panic!("{e}"));
+
+    assert_eq!(part1, S::part1(&parsed), "part 1");
+    assert_eq!(part2, S::part2(&parsed), "part 2");
+}
+
+/// Declares one test per input file of a day, pinning the answers to both parts.
+/// Paths are relative to the day's crate
+///
+/// ```ignore
+/// aoc_common::examples! {
+///     CampCleanup;
+///     example: "test.txt" => (Some(2), Some(4)),
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty; $($name:ident: $file:literal => ($part1:expr, $part2:expr)),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $file));
+
+                $crate::testing::check::<$solution>(input, $part1, $part2);
+            }
+        )*
+    };
+}
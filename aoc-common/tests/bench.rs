@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use aoc_common::bench::Stats;
+
+#[test]
+fn computes_summary_statistics() {
+    let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+
+    let stats = Stats::from_samples(&samples);
+
+    assert_eq!(stats.samples, 8);
+    assert_eq!(stats.mean, Duration::from_millis(5));
+    assert_eq!(
+        stats.median,
+        Duration::from_millis(4) + Duration::from_micros(500)
+    );
+    assert_eq!(stats.stddev, Duration::from_millis(2));
+    assert_eq!(stats.min, Duration::from_millis(2));
+    assert_eq!(stats.max, Duration::from_millis(9));
+}
+
+#[test]
+fn median_of_odd_samples_is_middle_element() {
+    let samples = [3, 1, 2].map(Duration::from_millis);
+
+    assert_eq!(
+        Stats::from_samples(&samples).median,
+        Duration::from_millis(2)
+    );
+}
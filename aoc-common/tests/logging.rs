@@ -0,0 +1,37 @@
+use aoc_common::logging::Verbosity;
+use clap::Parser;
+use log::LevelFilter;
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+fn level(args: &[&str]) -> LevelFilter {
+    let args = std::iter::once("test").chain(args.iter().copied());
+
+    Cli::parse_from(args).verbosity.level_filter()
+}
+
+#[test]
+fn only_warnings_by_default() {
+    assert_eq!(level(&[]), LevelFilter::Warn);
+}
+
+#[test]
+fn verbose_flags_raise_level() {
+    assert_eq!(level(&["-v"]), LevelFilter::Info);
+    assert_eq!(level(&["-vv"]), LevelFilter::Debug);
+    assert_eq!(level(&["-vvvv"]), LevelFilter::Trace);
+}
+
+#[test]
+fn quiet_silences_everything() {
+    assert_eq!(level(&["-q"]), LevelFilter::Off);
+}
+
+#[test]
+fn quiet_conflicts_with_verbose() {
+    assert!(Cli::try_parse_from(["test", "-q", "-v"]).is_err());
+}
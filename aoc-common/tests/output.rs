@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use aoc_common::{
+    input::InputSource,
+    output::{format_answer, Format},
+    Answer, Part,
+};
+
+fn answer() -> Answer {
+    Answer {
+        part: Part::Two,
+        answer: "MCD".into(),
+        duration: Duration::from_micros(42),
+    }
+}
+
+#[test]
+fn formats_text() {
+    let input = InputSource::File("test.txt".into());
+
+    assert_eq!(
+        format_answer(Format::Text, 5, &input, &answer()),
+        "Day 5 part 2: MCD"
+    );
+}
+
+#[test]
+fn formats_json_record() {
+    let input = InputSource::Stdin;
+
+    let record = format_answer(Format::Json, 5, &input, &answer());
+    let record: serde_json::Value = serde_json::from_str(&record).unwrap();
+
+    assert_eq!(record["day"], 5);
+    assert_eq!(record["part"], 2);
+    assert_eq!(record["answer"], "MCD");
+    assert_eq!(record["input"], "<stdin>");
+    assert_eq!(record["duration"], 42_000);
+}
@@ -0,0 +1,44 @@
+use aoc_common::ParseError;
+
+#[test]
+fn points_at_token() {
+    let line = "move 1 from 1 to 4";
+    let token = &line[17..];
+
+    let error = ParseError::at_token(5, line, token, "There are only 3 stacks");
+
+    assert_eq!(error.line(), 6);
+    assert_eq!(error.column(), 18);
+    assert_eq!(error.text(), line);
+}
+
+#[test]
+fn falls_back_to_whole_line_for_foreign_token() {
+    let error = ParseError::at_token(0, "addx 3", "3", "Not a substring slice");
+
+    assert_eq!(error.column(), 1);
+}
+
+#[test]
+fn renders_caret_snippet() {
+    let line = "2-4,6-x";
+    let error = ParseError::at_token(9, line, &line[6..], "Invalid section").with_file("test.txt");
+
+    assert_eq!(
+        error.to_string(),
+        "\
+error: Invalid section
+  --> test.txt:10:7
+   |
+10 | 2-4,6-x
+   |       ^"
+    );
+}
+
+#[test]
+fn points_past_end_of_input() {
+    let error = ParseError::at_end("a\nb\n", "Unexpected end of input");
+
+    assert_eq!(error.line(), 3);
+    assert_eq!(error.column(), 1);
+}
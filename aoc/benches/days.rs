@@ -0,0 +1,42 @@
+use std::{fs, hint::black_box};
+
+use aoc_common::Solution;
+use calorie_counting::CalorieCounting;
+use camp_cleanup::CampCleanup;
+use cathode_ray_tube::CathodeRayTube;
+use criterion::{criterion_group, criterion_main, Criterion};
+use no_space_left_on_device::NoSpaceLeftOnDevice;
+use rock_paper_scissors::RockPaperScissors;
+use rope_bridge::RopeBridge;
+use rucksack_reorganization::RucksackReorganization;
+use supply_stacks::SupplyStacks;
+use treetop_tree_house::TreetopTreeHouse;
+use tuning_trouble::TuningTrouble;
+
+fn bench_day<S: Solution>(c: &mut Criterion, name: &str, input: &str) {
+    let path = format!("{}/../{name}/{input}", env!("CARGO_MANIFEST_DIR"));
+    let input = fs::read_to_string(&path).unwrap_or_else(|e| panic!("Could not read {path}: {e}"));
+    let parsed = S::parse(&input).unwrap_or_else(|e| panic!("{}", e.with_file(&path)));
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<CalorieCounting>(c, "calorie-counting", "test.txt");
+    bench_day::<RockPaperScissors>(c, "rock-paper-scissors", "task.txt");
+    bench_day::<RucksackReorganization>(c, "rucksack-reorganization", "task.txt");
+    bench_day::<CampCleanup>(c, "camp-cleanup", "task.txt");
+    bench_day::<SupplyStacks>(c, "supply-stacks", "task.txt");
+    bench_day::<TuningTrouble>(c, "tuning-trouble", "task.txt");
+    bench_day::<NoSpaceLeftOnDevice>(c, "no-space-left-on-device", "task.txt");
+    bench_day::<TreetopTreeHouse>(c, "treetop-tree-house", "task.txt");
+    bench_day::<RopeBridge>(c, "rope-bridge", "task.txt");
+    bench_day::<CathodeRayTube>(c, "cathode-ray-tube", "task.txt");
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+use std::{io::BufRead, path::PathBuf};
+
+use aoc_common::{
+    bench::{measure, Timings},
+    solve_parts,
+    stream::{stream_parts, StreamError},
+    Answer, ParseError, Part, Solution,
+};
+use calorie_counting::CalorieCounting;
+use camp_cleanup::CampCleanup;
+use cathode_ray_tube::CathodeRayTube;
+use no_space_left_on_device::NoSpaceLeftOnDevice;
+use rock_paper_scissors::RockPaperScissors;
+use rope_bridge::RopeBridge;
+use rucksack_reorganization::RucksackReorganization;
+use supply_stacks::SupplyStacks;
+use treetop_tree_house::TreetopTreeHouse;
+use tuning_trouble::TuningTrouble;
+
+type StreamFn = fn(&mut dyn BufRead, &[Part]) -> Result<Vec<Answer>, StreamError>;
+
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    default_input: &'static str,
+
+    pub solve: fn(&str, &[Part]) -> Result<Vec<Answer>, ParseError>,
+    pub bench: fn(&str, usize) -> Result<Timings, ParseError>,
+    /// Only set for days that can be solved line by line
+    pub stream: Option<StreamFn>,
+}
+
+impl Day {
+    //Inputs live next to each day's crate, relative to the workspace root
+    pub fn default_input(&self) -> PathBuf {
+        [self.name, self.default_input].iter().collect()
+    }
+}
+
+macro_rules! day {
+    ($name:literal, $default_input:literal, $solution:ty) => {
+        Day {
+            number: <$solution as Solution>::DAY,
+            name: $name,
+            default_input: $default_input,
+            solve: solve_parts::<$solution>,
+            bench: measure::<$solution>,
+            stream: None,
+        }
+    };
+    ($name:literal, $default_input:literal, $solution:ty, streaming) => {
+        Day {
+            stream: Some(stream_parts::<$solution>),
+            ..day!($name, $default_input, $solution)
+        }
+    };
+}
+
+pub const DAYS: [Day; 10] = [
+    day!("calorie-counting", "test.txt", CalorieCounting, streaming),
+    day!("rock-paper-scissors", "task.txt", RockPaperScissors),
+    day!(
+        "rucksack-reorganization",
+        "task.txt",
+        RucksackReorganization,
+        streaming
+    ),
+    day!("camp-cleanup", "task.txt", CampCleanup, streaming),
+    day!("supply-stacks", "task.txt", SupplyStacks),
+    day!("tuning-trouble", "task.txt", TuningTrouble),
+    day!("no-space-left-on-device", "task.txt", NoSpaceLeftOnDevice),
+    day!("treetop-tree-house", "task.txt", TreetopTreeHouse),
+    day!("rope-bridge", "task.txt", RopeBridge, streaming),
+    day!("cathode-ray-tube", "task.txt", CathodeRayTube),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
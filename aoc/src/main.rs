@@ -0,0 +1,192 @@
+mod days;
+mod report;
+
+use std::{
+    error::Error,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    cli,
+    input::InputSource,
+    logging::Verbosity,
+    output::{self, Format},
+    Answer, Part,
+};
+use clap::{Args, Parser, Subcommand};
+use days::Day;
+use log::warn;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day
+    Run {
+        /// The day to run (1-25)
+        day: u8,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file, '-' for stdin. Falls back to $AOC_INPUT, then the day's task.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        #[command(flatten)]
+        options: RunOptions,
+    },
+    /// Run every day on its default input
+    All {
+        #[command(flatten)]
+        options: RunOptions,
+    },
+    /// List all available days
+    List,
+}
+
+#[derive(Args)]
+struct RunOptions {
+    /// Time parsing and both parts separately instead of printing answers
+    #[arg(long)]
+    bench: bool,
+
+    /// How often to run each stage when benchmarking
+    #[arg(long, default_value_t = 100)]
+    iterations: usize,
+
+    /// Output format. Diagnostics always go to stderr
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Read the input line by line instead of loading all of it into memory.
+    /// Days without a streaming solver still read the whole input
+    #[arg(long, conflicts_with = "bench")]
+    stream: bool,
+}
+
+fn solve_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    stream: bool,
+) -> Result<Vec<Answer>, Box<dyn Error>> {
+    match day.stream {
+        Some(stream_parts) if stream => {
+            let mut reader = source.open()?;
+            Ok(stream_parts(&mut reader, parts).map_err(|e| e.with_file(source))?)
+        }
+        _ => {
+            if stream {
+                warn!("Day {} can not be streamed, reading the whole input", day.number);
+            }
+
+            let input = source.read()?;
+            Ok((day.solve)(&input, parts).map_err(|e| e.with_file(source))?)
+        }
+    }
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    options: &RunOptions,
+) -> Result<Duration, Box<dyn Error>> {
+    let start = Instant::now();
+    let answers = solve_day(day, parts, source, options.stream)?;
+    let elapsed = start.elapsed();
+
+    let format = options.format;
+    output::print_answers(format, day.number, source, &answers);
+    if format == Format::Text {
+        println!("--- took {elapsed:?} ---");
+    }
+
+    Ok(elapsed)
+}
+
+fn bench_day(day: &Day, source: &InputSource, options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    let input = source.read()?;
+
+    let timings = (day.bench)(&input, options.iterations).map_err(|e| e.with_file(source))?;
+    report::print_timings(day, &timings, options.format);
+
+    Ok(())
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            options,
+        } => {
+            let Some(day) = days::find(day) else {
+                return Err(format!("Day {day} is not solved yet").into());
+            };
+
+            let parts = match part {
+                Some(1) => vec![Part::One],
+                Some(_) => vec![Part::Two],
+                None => Part::BOTH.to_vec(),
+            };
+            let input = InputSource::resolve(input, day.default_input());
+
+            if options.bench {
+                bench_day(day, &input, &options)?;
+            } else {
+                run_day(day, &parts, &input, &options)?;
+            }
+        }
+        Command::All { options } if options.bench => {
+            for day in &days::DAYS {
+                bench_day(day, &InputSource::File(day.default_input()), &options)?;
+            }
+        }
+        Command::All { options } => {
+            let timings = days::DAYS
+                .iter()
+                .map(|day| {
+                    let input = InputSource::File(day.default_input());
+                    Ok((day, run_day(day, &Part::BOTH, &input, &options)?))
+                })
+                .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+            if options.format == Format::Json {
+                return Ok(());
+            }
+
+            println!();
+            for (day, elapsed) in timings {
+                println!("Day {:>2} {:<25} {elapsed:?}", day.number, day.name);
+            }
+        }
+        Command::List => {
+            for day in &days::DAYS {
+                println!("Day {:>2} {}", day.number, day.name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    cli.verbosity.init_logger();
+
+    cli::exit_code(run(cli))
+}
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use aoc_common::{
+    bench::{Stats, Timings},
+    output::Format,
+};
+use serde_json::json;
+
+use crate::days::Day;
+
+fn stages(timings: &Timings) -> [(&'static str, &Stats); 3] {
+    [
+        ("parse", &timings.parse),
+        ("part1", &timings.part1),
+        ("part2", &timings.part2),
+    ]
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+pub fn print_timings(day: &Day, timings: &Timings, format: Format) {
+    match format {
+        Format::Text => {
+            println!("Day {:>2} {}", day.number, day.name);
+            for (stage, stats) in stages(timings) {
+                println!(
+                    "  {stage:<6} mean {:>12?}  median {:>12?}  stddev {:>12?}  ({} samples)",
+                    stats.mean, stats.median, stats.stddev, stats.samples
+                );
+            }
+        }
+        Format::Json => {
+            for (stage, stats) in stages(timings) {
+                let record = json!({
+                    "day": day.number,
+                    "name": day.name,
+                    "stage": stage,
+                    "samples": stats.samples,
+                    "mean_ns": nanos(stats.mean),
+                    "median_ns": nanos(stats.median),
+                    "stddev_ns": nanos(stats.stddev),
+                    "min_ns": nanos(stats.min),
+                    "max_ns": nanos(stats.max),
+                });
+                println!("{record}");
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::{error::Error, fmt::Debug, fmt::Display};
+
+/// An integer type calorie totals are summed in. Sums are checked, so a total that does not fit
+/// is reported as an [`Overflow`] instead of wrapping around
+pub trait Accumulator: Copy + Ord + Default + Debug + Display + From<u32> + TryInto<u64> {
+    /// The name of the type, for error messages
+    const NAME: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sum(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+        values
+            .into_iter()
+            .try_fold(Self::default(), |sum, value| sum.checked_add(value))
+    }
+}
+
+macro_rules! accumulator {
+    ($($t:ty),*) => {
+        $(
+            impl Accumulator for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+accumulator!(u32, u64, u128);
+
+/// A calorie total that does not fit into the accumulator type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Overflow {
+    /// The items of the elf with this 0-based index
+    Elf { index: usize, width: &'static str },
+    /// The combined total of the top `k` elves
+    Top { k: usize, width: &'static str },
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Overflow::Elf { index, width } => write!(
+                f,
+                "The calories carried by elf #{} do not fit into {width}, try a wider --width",
+                index + 1
+            ),
+            Overflow::Top { k, width } => write!(
+                f,
+                "The calories carried by the top {k} elves do not fit into {width}, try a wider --width"
+            ),
+        }
+    }
+}
+
+impl Error for Overflow {}
@@ -0,0 +1,206 @@
+mod accumulator;
+mod parse;
+mod report;
+mod top_k;
+
+use std::{collections::HashMap, fmt::Display, marker::PhantomData};
+
+use aoc_common::{stream::StreamingSolution, ParseError, Solution};
+use log::debug;
+
+pub use accumulator::{Accumulator, Overflow};
+pub use parse::{parse_elves, CSV_HEADER};
+pub use report::{Bucket, ElfSummary, Report, PERCENTILES};
+pub use top_k::{top_k, TopK};
+
+use parse::{add_calories, parse_line, Entry, Layout};
+
+/// One elf's inventory. `index` is the 0-based position of the elf in the input,
+/// or the order in which the ids first appear in a CSV inventory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u32>,
+}
+
+impl Elf {
+    pub fn total<T: Accumulator>(&self) -> Result<T, Overflow> {
+        T::checked_sum(self.items.iter().map(|&calories| T::from(calories))).ok_or(Overflow::Elf {
+            index: self.index,
+            width: T::NAME,
+        })
+    }
+}
+
+/// The elves carrying the most calories, most first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking<T = u32> {
+    /// `(index, calories)` per elf, with the 0-based index of the elf in the input
+    pub elves: Vec<(usize, T)>,
+    pub total: T,
+}
+
+impl<T: Accumulator> TryFrom<TopK<T>> for Ranking<T> {
+    type Error = Overflow;
+
+    fn try_from(top: TopK<T>) -> Result<Self, Overflow> {
+        let k = top.k();
+        let elves = top.into_sorted_vec();
+        let total = T::checked_sum(elves.iter().map(|&(_, calories)| calories))
+            .ok_or(Overflow::Top { k, width: T::NAME })?;
+
+        Ok(Self { elves, total })
+    }
+}
+
+impl<T: Display> Display for Ranking<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (rank, (index, calories)) in self.elves.iter().enumerate() {
+            writeln!(f, "{:>3}. elf #{:<6} {calories}", rank + 1, index + 1)?;
+        }
+        write!(f, "Total: {}", self.total)
+    }
+}
+
+/// Ranks the `k` elves carrying the most calories
+pub fn top_elves<T: Accumulator>(elves: &[Elf], k: usize) -> Result<Ranking<T>, Overflow> {
+    let mut top = TopK::new(k);
+    for elf in elves {
+        top.push(elf.index, elf.total()?);
+    }
+
+    top.try_into()
+}
+
+/// Day 1, with calorie totals summed in `T`
+pub struct CalorieCountingWith<T>(PhantomData<T>);
+
+pub type CalorieCounting = CalorieCountingWith<u32>;
+
+impl<T: Accumulator> Solution for CalorieCountingWith<T> {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<Elf>;
+    type Answer1 = T;
+    type Answer2 = T;
+
+    fn parse(input: &str) -> Result<Vec<Elf>, ParseError> {
+        let elves = parse_elves::<T>(input)?;
+
+        //Both answers are sums of the top three, so they fit if these do. Rankings and
+        //reports use parse_elves instead, since they do not need the answers
+        top_elves::<T>(&elves, 3).map_err(|e| ParseError::at_end(input, e.to_string()))?;
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Vec<Elf>) -> T {
+        top_elves(elves, 1)
+            .expect("totals are checked while parsing")
+            .total
+    }
+
+    fn part2(elves: &Vec<Elf>) -> T {
+        let top_three = top_elves::<T>(elves, 3).expect("totals are checked while parsing");
+        debug!("The top three elves are {:?}", top_three.elves);
+
+        top_three.total
+    }
+}
+
+/// The elf currently being counted and the largest totals so far.
+/// CSV inventories keep the total of every id, since rows of one elf may be anywhere
+#[derive(Debug, Clone)]
+pub struct RunningTotals<T = u32> {
+    lines: usize,
+    layout: Option<Layout>,
+    elves: usize,
+    current: Option<T>,
+    ids: HashMap<String, (usize, T)>,
+    top: TopK<T>,
+}
+
+impl<T: Accumulator> Default for RunningTotals<T> {
+    fn default() -> Self {
+        Self::with_top(3)
+    }
+}
+
+impl<T: Accumulator> RunningTotals<T> {
+    /// Remembers the `k` largest totals. The answers to both parts need `k` to be at least 3
+    pub fn with_top(k: usize) -> Self {
+        Self {
+            lines: 0,
+            layout: None,
+            elves: 0,
+            current: None,
+            ids: HashMap::new(),
+            top: TopK::new(k),
+        }
+    }
+
+    fn finish_elf(&mut self) {
+        if let Some(total) = self.current.take() {
+            self.top.push(self.elves, total);
+            self.elves += 1;
+        }
+    }
+
+    /// Counts the last elf and ranks the largest totals
+    pub fn into_ranking(mut self) -> Result<Ranking<T>, Overflow> {
+        self.finish_elf();
+        for (index, total) in self.ids.into_values() {
+            self.top.push(index, total);
+        }
+
+        self.top.try_into()
+    }
+}
+
+impl<T: Accumulator> StreamingSolution for CalorieCountingWith<T> {
+    type State = RunningTotals<T>;
+
+    fn feed(totals: &mut RunningTotals<T>, index: usize, line: &str) -> Result<(), ParseError> {
+        totals.lines = index + 1;
+
+        match parse_line(&mut totals.layout, index, line)? {
+            Entry::Blank => totals.finish_elf(),
+            Entry::Header => {}
+            Entry::Item(calories) => {
+                let elf = totals.elves;
+                let total = totals.current.get_or_insert_with(T::default);
+                add_calories(total, calories, elf, index, line)?;
+            }
+            Entry::Row { id, calories } => {
+                let next = totals.ids.len();
+                let (elf, total) = match totals.ids.get_mut(id) {
+                    Some(entry) => entry,
+                    None => totals
+                        .ids
+                        .entry(id.to_owned())
+                        .or_insert((next, T::default())),
+                };
+                add_calories(total, calories, *elf, index, line)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(totals: RunningTotals<T>) -> Result<(T, T), ParseError> {
+        let lines = totals.lines;
+        let ranking = totals
+            .into_ranking()
+            .map_err(|e| ParseError::at_line(lines, "", e.to_string()))?;
+        debug!("The top three elves are {:?}", ranking.elves);
+
+        let most = ranking.elves.first().map(|&(_, calories)| calories);
+        let top_three = T::checked_sum(ranking.elves.iter().take(3).map(|&(_, calories)| calories))
+            .ok_or(Overflow::Top {
+                k: 3,
+                width: T::NAME,
+            })
+            .map_err(|e| ParseError::at_line(lines, "", e.to_string()))?;
+
+        Ok((most.unwrap_or_default(), top_three))
+    }
+}
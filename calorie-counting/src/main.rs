@@ -1,31 +1,160 @@
-use std::{
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
+use std::{error::Error, process::ExitCode};
+
+use aoc_common::{
+    cli::{self, DayArgs, StreamArgs},
+    input::InputSource,
+    output::Format,
+    stream::feed_stream,
+};
+use calorie_counting::{
+    parse_elves, top_elves, Accumulator, CalorieCountingWith, Ranking, Report, RunningTotals,
 };
+use clap::{Parser, ValueEnum};
+use serde_json::{json, Value};
+
+const DEFAULT_INPUT: &str = "test.txt";
+
+/// The integer type calorie totals are summed in
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+enum Width {
+    #[default]
+    U32,
+    U64,
+    U128,
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: DayArgs,
+
+    #[command(flatten)]
+    streaming: StreamArgs,
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = BufReader::new(File::open("test.txt")?);
+    /// Rank the N elves carrying the most calories instead of solving both parts
+    #[arg(long, value_name = "N")]
+    top: Option<usize>,
 
-    let lines = file.lines().flatten().collect::<Vec<_>>();
+    /// Print per-elf totals, statistics and a histogram instead of solving both parts
+    #[arg(long, conflicts_with_all = ["top", "stream"])]
+    report: bool,
 
-    let mut elves = lines
-        .split(|line| line.is_empty())
-        .map(|chunk| {
-            chunk
+    /// Sum calories in a wider integer type. Totals that do not fit are reported as an error
+    #[arg(long, value_enum, default_value_t)]
+    width: Width,
+}
+
+//JSON numbers only go up to u64, larger totals are written as strings
+fn number<T: Accumulator>(value: T) -> Value {
+    match value.try_into() {
+        Ok(value) => Value::from(value),
+        Err(_) => Value::from(value.to_string()),
+    }
+}
+
+fn rank<T: Accumulator>(
+    source: &InputSource,
+    k: usize,
+    stream: bool,
+) -> Result<Ranking<T>, Box<dyn Error>> {
+    if stream {
+        let mut totals = RunningTotals::<T>::with_top(k);
+        feed_stream::<CalorieCountingWith<T>>(source.open()?, &mut totals)
+            .map_err(|e| e.with_file(source))?;
+
+        return Ok(totals.into_ranking()?);
+    }
+
+    let elves = parse_elves::<T>(&source.read()?).map_err(|e| e.with_file(source))?;
+    Ok(top_elves(&elves, k)?)
+}
+
+fn print_ranking<T: Accumulator>(format: Format, ranking: &Ranking<T>) {
+    match format {
+        Format::Text => println!("{ranking}"),
+        Format::Json => {
+            let elves = ranking
+                .elves
                 .iter()
-                .flat_map(|line| line.parse::<u32>())
-                .sum::<u32>()
-        })
-        .collect::<Vec<_>>();
+                .map(|&(index, calories)| json!({ "elf": index + 1, "calories": number(calories) }))
+                .collect::<Vec<_>>();
+
+            println!(
+                "{}",
+                json!({ "top": elves, "total": number(ranking.total) })
+            );
+        }
+    }
+}
 
-    let max = elves.iter().max().unwrap().clone();
+fn report<T: Accumulator>(source: &InputSource) -> Result<Report, Box<dyn Error>> {
+    let elves = parse_elves::<T>(&source.read()?).map_err(|e| e.with_file(source))?;
 
-    elves.sort_unstable();
+    Ok(Report::new(&elves))
+}
 
-    let max_sum = elves.iter().rev().take(3).sum::<u32>();
+fn print_report(format: Format, report: &Report) {
+    match format {
+        Format::Text => print!("{report}"),
+        Format::Json => {
+            let elves = report
+                .elves
+                .iter()
+                .map(|elf| json!({ "elf": elf.index + 1, "items": elf.items, "calories": number(elf.total) }))
+                .collect::<Vec<_>>();
+            let percentiles = report
+                .percentiles
+                .iter()
+                .map(|&(p, total)| (format!("p{p}"), number(total)))
+                .collect::<serde_json::Map<_, _>>();
+            let histogram = report
+                .histogram
+                .iter()
+                .map(
+                    |b| json!({ "start": number(b.start), "end": number(b.end), "count": b.count }),
+                )
+                .collect::<Vec<_>>();
+
+            println!(
+                "{}",
+                json!({
+                    "elves": elves,
+                    "mean": report.mean,
+                    "median": report.median,
+                    "percentiles": percentiles,
+                    "histogram": histogram,
+                })
+            );
+        }
+    }
+}
+
+fn run<T: Accumulator>(cli: &Cli) -> ExitCode {
+    if !cli.report && cli.top.is_none() {
+        return cli::run_streaming::<CalorieCountingWith<T>>(
+            &cli.args,
+            cli.streaming.stream,
+            DEFAULT_INPUT,
+        );
+    }
+
+    let source = cli.args.init(DEFAULT_INPUT);
+    let format = cli.args.format;
+    let result = match cli.top {
+        Some(k) => rank::<T>(&source, k, cli.streaming.stream)
+            .map(|ranking| print_ranking(format, &ranking)),
+        None => report::<T>(&source).map(|report| print_report(format, &report)),
+    };
+
+    cli::exit_code(result)
+}
 
-    println!("{max} {max_sum}");
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    Ok(())
+    match cli.width {
+        Width::U32 => run::<u32>(&cli),
+        Width::U64 => run::<u64>(&cli),
+        Width::U128 => run::<u128>(&cli),
+    }
 }
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use aoc_common::ParseError;
+
+use crate::{Accumulator, Elf, Overflow};
+
+/// The header a CSV inventory may start with
+pub const CSV_HEADER: &str = "elf_id,calories";
+
+/// How an inventory is laid out, detected from its first non-blank line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Layout {
+    /// One calorie count per line, elves separated by blank lines
+    Groups,
+    /// `elf_id,calories` rows, aggregated by id
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Entry<'a> {
+    /// An empty or whitespace-only line
+    Blank,
+    Header,
+    /// An item of the current group
+    Item(u32),
+    /// An item of the elf called `id`
+    Row {
+        id: &'a str,
+        calories: u32,
+    },
+}
+
+fn parse_calories(index: usize, line: &str, token: &str) -> Result<u32, ParseError> {
+    token.parse::<u32>().map_err(|e| {
+        ParseError::at_token(index, line, token, format!("Invalid calorie count: {e}"))
+    })
+}
+
+/// Reads one line of an inventory, detecting the layout on the first non-blank line
+pub(crate) fn parse_line<'a>(
+    layout: &mut Option<Layout>,
+    index: usize,
+    line: &'a str,
+) -> Result<Entry<'a>, ParseError> {
+    use Layout::*;
+
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        return Ok(Entry::Blank);
+    }
+
+    let detected = layout.is_none();
+    let layout = *layout.get_or_insert(if trimmed.contains(',') { Csv } else { Groups });
+
+    match layout {
+        Groups => Ok(Entry::Item(parse_calories(index, line, trimmed)?)),
+        Csv if detected && trimmed.replace(' ', "").eq_ignore_ascii_case(CSV_HEADER) => {
+            Ok(Entry::Header)
+        }
+        Csv => {
+            let Some((id, calories)) = trimmed.split_once(',') else {
+                return Err(ParseError::at_token(
+                    index,
+                    line,
+                    trimmed,
+                    "Expected 'elf_id,calories'",
+                ));
+            };
+
+            let id = id.trim();
+            if id.is_empty() {
+                return Err(ParseError::at_token(index, line, trimmed, "Missing elf id"));
+            }
+
+            Ok(Entry::Row {
+                id,
+                calories: parse_calories(index, line, calories.trim())?,
+            })
+        }
+    }
+}
+
+/// Adds `calories` to the total of elf `elf`, pointing at the offending line if it overflows
+pub(crate) fn add_calories<T: Accumulator>(
+    total: &mut T,
+    calories: u32,
+    elf: usize,
+    index: usize,
+    line: &str,
+) -> Result<(), ParseError> {
+    *total = total.checked_add(T::from(calories)).ok_or_else(|| {
+        let overflow = Overflow::Elf {
+            index: elf,
+            width: T::NAME,
+        };
+        ParseError::at_line(index, line, overflow.to_string())
+    })?;
+    Ok(())
+}
+
+/// Parses a whole inventory, either blank-line separated groups or CSV rows.
+/// Runs of blank lines count as a single separator, so no elf is ever empty.
+/// Only the total of each single elf has to fit in `T`
+pub fn parse_elves<T: Accumulator>(input: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut layout = None;
+    let mut elves = Vec::<Elf>::new();
+    let mut totals = Vec::<T>::new();
+
+    //Groups: whether the last elf is still open. CSV: where each id is in `elves`
+    let mut in_group = false;
+    let mut ids = HashMap::<&str, usize>::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let (elf, calories) = match parse_line(&mut layout, index, line)? {
+            Entry::Blank => {
+                in_group = false;
+                continue;
+            }
+            Entry::Header => continue,
+            Entry::Item(calories) => {
+                if !in_group {
+                    in_group = true;
+                    elves.push(Elf {
+                        index: elves.len(),
+                        items: vec![],
+                    });
+                    totals.push(T::default());
+                }
+                (elves.len() - 1, calories)
+            }
+            Entry::Row { id, calories } => {
+                let next = elves.len();
+                let elf = *ids.entry(id).or_insert(next);
+                if elf == next {
+                    elves.push(Elf {
+                        index: next,
+                        items: vec![],
+                    });
+                    totals.push(T::default());
+                }
+                (elf, calories)
+            }
+        };
+
+        add_calories(&mut totals[elf], calories, elf, index, line)?;
+        elves[elf].items.push(calories);
+    }
+
+    Ok(elves)
+}
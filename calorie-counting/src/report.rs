@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use crate::Elf;
+
+/// The percentiles listed in a report, besides the median
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+const HISTOGRAM_BUCKETS: u128 = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfSummary {
+    pub index: usize,
+    pub items: usize,
+    pub total: u128,
+}
+
+/// The number of elves whose total lies in `start..=end`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u128,
+    pub end: u128,
+    pub count: usize,
+}
+
+/// Statistics over the calorie totals of all elves
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub elves: Vec<ElfSummary>,
+    pub mean: f64,
+    pub median: f64,
+    /// `(percentile, total)` for each of [`PERCENTILES`], using the nearest-rank method
+    pub percentiles: Vec<(u8, u128)>,
+    pub histogram: Vec<Bucket>,
+}
+
+impl Report {
+    pub fn new(elves: &[Elf]) -> Self {
+        let summaries = elves
+            .iter()
+            .map(|elf| ElfSummary {
+                index: elf.index,
+                items: elf.items.len(),
+                //Even billions of items of u32::MAX calories fit
+                total: elf.total().expect("u128 totals can not overflow"),
+            })
+            .collect::<Vec<_>>();
+
+        let mut totals = summaries.iter().map(|elf| elf.total).collect::<Vec<_>>();
+        totals.sort_unstable();
+
+        Self {
+            elves: summaries,
+            mean: mean(&totals),
+            median: median(&totals),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&totals, p)))
+                .collect(),
+            histogram: histogram(&totals),
+        }
+    }
+}
+
+fn mean(totals: &[u128]) -> f64 {
+    if totals.is_empty() {
+        return 0.0;
+    }
+
+    totals.iter().map(|&total| total as f64).sum::<f64>() / totals.len() as f64
+}
+
+fn median(sorted: &[u128]) -> f64 {
+    let n = sorted.len();
+
+    match n {
+        0 => 0.0,
+        _ if n.is_multiple_of(2) => (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0,
+        _ => sorted[n / 2] as f64,
+    }
+}
+
+fn percentile(sorted: &[u128], p: u8) -> u128 {
+    if sorted.is_empty() {
+        return 0;
+    }
+
+    let rank = (p as usize * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn histogram(sorted: &[u128]) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return vec![];
+    };
+
+    //Buckets are equally wide and together cover min..=max
+    let width = (max - min) / HISTOGRAM_BUCKETS + 1;
+    let num_buckets = (max - min) / width + 1;
+
+    let mut buckets = (0..num_buckets)
+        .map(|i| Bucket {
+            start: min + i * width,
+            end: (min + (i + 1) * width - 1).min(max),
+            count: 0,
+        })
+        .collect::<Vec<_>>();
+
+    for &total in sorted {
+        buckets[((total - min) / width) as usize].count += 1;
+    }
+
+    buckets
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:>8} {:>6} {:>10}", "Elf", "Items", "Calories")?;
+        for elf in &self.elves {
+            writeln!(f, "{:>8} {:>6} {:>10}", elf.index + 1, elf.items, elf.total)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Elves:  {}", self.elves.len())?;
+        writeln!(f, "Mean:   {:.1}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+        for (p, total) in &self.percentiles {
+            writeln!(f, "p{p:<6} {total}")?;
+        }
+
+        writeln!(f)?;
+        let most = self.histogram.iter().map(|b| b.count).max().unwrap_or(1);
+        for bucket in &self.histogram {
+            let bar = "#".repeat(bucket.count * HISTOGRAM_WIDTH / most);
+            writeln!(
+                f,
+                "{:>10} - {:>10} | {bar} {}",
+                bucket.start, bucket.end, bucket.count
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Keeps the `k` largest values pushed into it, using a min-heap of at most `k` entries.
+/// Equal values are ranked by their index, so earlier ones win
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+    k: usize,
+    //The smallest kept value is on top, so it can be replaced in O(log k)
+    heap: BinaryHeap<Reverse<(T, Reverse<usize>)>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    pub fn push(&mut self, index: usize, value: T) {
+        let entry = Reverse((value, Reverse(index)));
+
+        if self.heap.len() < self.k {
+            self.heap.push(entry);
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if entry < *smallest {
+                *smallest = entry;
+            }
+        }
+    }
+
+    /// The kept values as `(index, value)`, largest first
+    pub fn into_sorted_vec(self) -> Vec<(usize, T)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((value, Reverse(index)))| (index, value))
+            .collect()
+    }
+}
+
+/// The `k` largest items of `iter` together with their position in it, largest first.
+/// Runs in O(n log k) time and O(k) memory
+pub fn top_k<T: Ord>(iter: impl IntoIterator<Item = T>, k: usize) -> Vec<(usize, T)> {
+    let mut top = TopK::new(k);
+
+    for (index, value) in iter.into_iter().enumerate() {
+        top.push(index, value);
+    }
+
+    top.into_sorted_vec()
+}
@@ -0,0 +1,6 @@
+use calorie_counting::CalorieCounting;
+
+aoc_common::examples! {
+    CalorieCounting;
+    example: "test.txt" => (Some(24000), Some(45000)),
+}
@@ -0,0 +1,75 @@
+use aoc_common::{testing::check_stream, Solution};
+use calorie_counting::{CalorieCounting, Elf};
+
+fn totals(input: &str) -> Vec<u32> {
+    CalorieCounting::parse(input)
+        .unwrap()
+        .iter()
+        .map(|elf| elf.total().unwrap())
+        .collect()
+}
+
+#[test]
+fn whitespace_lines_separate_elves() {
+    assert_eq!(totals("1\n2\n  \n3\n\t\n4\n"), [3, 3, 4]);
+}
+
+#[test]
+fn crlf_and_repeated_separators() {
+    let input = "1\r\n2\r\n\r\n\r\n3\r\n\r\n";
+
+    assert_eq!(totals(input), [3, 3]);
+    check_stream::<CalorieCounting>(input);
+}
+
+#[test]
+fn leading_and_trailing_separators_add_no_elves() {
+    assert_eq!(totals("\n\n5\n\n\n"), [5]);
+    assert!(totals("").is_empty());
+    assert!(totals(" \n\n").is_empty());
+}
+
+#[test]
+fn csv_rows_are_aggregated_by_id() {
+    let input = "elf_id,calories\nbob,1000\nalice,2000\nbob, 3000\ncarol,500\n";
+    let elves = CalorieCounting::parse(input).unwrap();
+
+    assert_eq!(
+        elves,
+        [
+            Elf {
+                index: 0,
+                items: vec![1000, 3000]
+            },
+            Elf {
+                index: 1,
+                items: vec![2000]
+            },
+            Elf {
+                index: 2,
+                items: vec![500]
+            },
+        ]
+    );
+    assert_eq!(CalorieCounting::part1(&elves), 4000);
+    assert_eq!(CalorieCounting::part2(&elves), 6500);
+    check_stream::<CalorieCounting>(input);
+}
+
+#[test]
+fn csv_header_is_optional() {
+    assert_eq!(totals("1,10\r\n2,20\r\n\r\n1,5\r\n"), [15, 20]);
+}
+
+#[test]
+fn csv_errors_point_at_the_field() {
+    let error = CalorieCounting::parse("elf_id,calories\n7,12x\n").unwrap_err();
+    assert_eq!((error.line(), error.column()), (2, 3));
+
+    let error = CalorieCounting::parse("7,12\n,4\n").unwrap_err();
+    assert_eq!(error.line(), 2);
+    assert_eq!(error.message(), "Missing elf id");
+
+    let error = CalorieCounting::parse("7,12\n30\n").unwrap_err();
+    assert_eq!(error.line(), 2);
+}
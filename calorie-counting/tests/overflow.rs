@@ -0,0 +1,77 @@
+use aoc_common::{
+    stream::{solve_stream, StreamError},
+    Solution,
+};
+use calorie_counting::{
+    parse_elves, top_elves, CalorieCounting, CalorieCountingWith, Elf, Overflow, Report,
+};
+
+const BIG: &str = "4294967295\n\n4000000000\n1\n";
+const TOO_BIG: &str = "1\n\n4294967295\n1\n";
+
+#[test]
+fn reports_the_elf_that_overflowed() {
+    let error = CalorieCounting::parse(TOO_BIG).unwrap_err();
+
+    assert_eq!((error.line(), error.text()), (4, "1"));
+    assert!(error.message().contains("elf #2"), "{}", error.message());
+    assert!(error.message().contains("u32"), "{}", error.message());
+}
+
+#[test]
+fn reports_overflowing_answers() {
+    //Every elf fits, but not the top three together
+    let error = CalorieCounting::parse(BIG).unwrap_err();
+
+    assert!(error.message().contains("top 3"), "{}", error.message());
+}
+
+#[test]
+fn rankings_only_need_single_elves_to_fit() {
+    let elves = parse_elves::<u32>(BIG).unwrap();
+
+    assert_eq!(top_elves::<u32>(&elves, 1).unwrap().total, u32::MAX);
+    assert_eq!(Report::new(&elves).elves.len(), 2);
+    assert_eq!(parse_elves::<u32>(TOO_BIG).unwrap_err().line(), 4);
+}
+
+#[test]
+fn wider_accumulators_fit() {
+    let elves = CalorieCountingWith::<u64>::parse(BIG).unwrap();
+
+    assert_eq!(CalorieCountingWith::<u64>::part1(&elves), 4_294_967_295);
+    assert_eq!(CalorieCountingWith::<u64>::part2(&elves), 8_294_967_296);
+    assert_eq!(
+        CalorieCountingWith::<u128>::part2(&CalorieCountingWith::<u128>::parse(TOO_BIG).unwrap()),
+        4_294_967_297
+    );
+}
+
+#[test]
+fn streaming_reports_overflow() {
+    let Err(StreamError::Parse(error)) = solve_stream::<CalorieCounting>(TOO_BIG.as_bytes()) else {
+        panic!("expected an overflow")
+    };
+    assert_eq!(error.line(), 4);
+
+    let Err(StreamError::Parse(error)) = solve_stream::<CalorieCounting>(BIG.as_bytes()) else {
+        panic!("expected an overflow")
+    };
+    assert_eq!(error.line(), 5);
+
+    let answers = solve_stream::<CalorieCountingWith<u64>>(BIG.as_bytes()).unwrap();
+    assert_eq!(answers, (4_294_967_295, 8_294_967_296));
+}
+
+#[test]
+fn ranking_overflow_names_k() {
+    let elves = [u32::MAX, 1].map(|calories| Elf {
+        index: 0,
+        items: vec![calories],
+    });
+
+    assert_eq!(
+        top_elves::<u32>(&elves, 2),
+        Err(Overflow::Top { k: 2, width: "u32" })
+    );
+}
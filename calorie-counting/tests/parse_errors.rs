@@ -0,0 +1,10 @@
+use aoc_common::Solution;
+use calorie_counting::CalorieCounting;
+
+#[test]
+fn rejects_malformed_count() {
+    let error = CalorieCounting::parse("1000\n2000\n\n3O00\n").unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (4, 1));
+    assert_eq!(error.text(), "3O00");
+}
@@ -0,0 +1,79 @@
+use aoc_common::Solution;
+use calorie_counting::{Bucket, CalorieCounting, Elf, ElfSummary, Report};
+
+fn elves(totals: &[u32]) -> Vec<Elf> {
+    totals
+        .iter()
+        .enumerate()
+        .map(|(index, &total)| Elf {
+            index,
+            items: vec![total],
+        })
+        .collect()
+}
+
+#[test]
+fn keeps_every_elf_and_its_items() {
+    let elves = CalorieCounting::parse(include_str!("../test.txt")).unwrap();
+
+    assert_eq!(elves.len(), 5);
+    assert_eq!(
+        elves[0],
+        Elf {
+            index: 0,
+            items: vec![1000, 2000, 3000]
+        }
+    );
+    assert_eq!(elves[3].total::<u32>(), Ok(24000));
+}
+
+#[test]
+fn summarises_the_example() {
+    let elves = CalorieCounting::parse(include_str!("../test.txt")).unwrap();
+    let report = Report::new(&elves);
+
+    assert_eq!(
+        report.elves[1],
+        ElfSummary {
+            index: 1,
+            items: 1,
+            total: 4000
+        }
+    );
+    assert_eq!(report.mean, 11000.0);
+    assert_eq!(report.median, 10000.0);
+    assert_eq!(report.percentiles[0], (10, 4000));
+    assert_eq!(report.percentiles.last(), Some(&(99, 24000)));
+}
+
+#[test]
+fn median_of_an_even_number_of_elves() {
+    let report = Report::new(&elves(&[4, 1, 3, 2]));
+
+    assert_eq!(report.median, 2.5);
+    assert_eq!(report.percentiles[1], (25, 1));
+}
+
+#[test]
+fn histogram_covers_all_totals() {
+    let report = Report::new(&elves(&[0, 5, 9, 10, 25]));
+
+    assert_eq!(report.histogram.iter().map(|b| b.count).sum::<usize>(), 5);
+    assert_eq!(
+        report.histogram.first(),
+        Some(&Bucket {
+            start: 0,
+            end: 2,
+            count: 1
+        })
+    );
+    assert_eq!(report.histogram.last().map(|b| b.end), Some(25));
+}
+
+#[test]
+fn empty_reports_do_not_panic() {
+    let report = Report::new(&[]);
+
+    assert_eq!(report.mean, 0.0);
+    assert!(report.histogram.is_empty());
+}
@@ -0,0 +1,26 @@
+use aoc_common::{
+    stream::{solve_stream, StreamError},
+    testing::check_stream,
+};
+use calorie_counting::CalorieCounting;
+
+#[test]
+fn example() {
+    check_stream::<CalorieCounting>(include_str!("../test.txt"));
+}
+
+#[test]
+fn handles_crlf_and_trailing_separators() {
+    check_stream::<CalorieCounting>("1000\r\n2000\r\n\r\n4000\r\n\r\n5000\r\n6000\r\n\r\n");
+}
+
+#[test]
+fn reports_the_failing_line() {
+    let error = solve_stream::<CalorieCounting>("1000\n\n3O00\n".as_bytes()).unwrap_err();
+
+    let StreamError::Parse(error) = error else {
+        panic!("{error}")
+    };
+    assert_eq!((error.line(), error.column()), (3, 1));
+    assert_eq!(error.text(), "3O00");
+}
@@ -0,0 +1,41 @@
+use aoc_common::{stream::feed_stream, Solution};
+use calorie_counting::{top_elves, top_k, CalorieCounting, RunningTotals};
+
+#[test]
+fn keeps_the_largest_items_in_order() {
+    let top = top_k([5, 1, 9, 3, 7], 3);
+
+    assert_eq!(top, [(2, 9), (4, 7), (0, 5)]);
+}
+
+#[test]
+fn prefers_earlier_items_on_ties() {
+    assert_eq!(top_k([2, 4, 4, 1, 4], 2), [(1, 4), (2, 4)]);
+}
+
+#[test]
+fn handles_small_and_empty_inputs() {
+    assert_eq!(top_k([3, 8], 5), [(1, 8), (0, 3)]);
+    assert_eq!(top_k(Vec::<u32>::new(), 3), []);
+    assert_eq!(top_k([1, 2, 3], 0), []);
+}
+
+#[test]
+fn ranks_the_example_elves() {
+    let elves = CalorieCounting::parse(include_str!("../test.txt")).unwrap();
+    let ranking = top_elves::<u32>(&elves, 3).unwrap();
+
+    assert_eq!(ranking.elves, [(3, 24000), (2, 11000), (4, 10000)]);
+    assert_eq!(ranking.total, 45000);
+}
+
+#[test]
+fn streaming_ranking_matches() {
+    let input = include_str!("../test.txt");
+
+    let mut totals = RunningTotals::with_top(4);
+    feed_stream::<CalorieCounting>(input.as_bytes(), &mut totals).unwrap();
+
+    let elves = CalorieCounting::parse(input).unwrap();
+    assert_eq!(totals.into_ranking(), top_elves(&elves, 4));
+}
@@ -0,0 +1,120 @@
+use std::{collections::BTreeSet, fmt::Display, ops::RangeInclusive};
+
+use crate::{Crew, SectionId};
+
+/// One elf's assignment and where it is in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    /// 1-based line number
+    pub line: usize,
+    /// 0-based position of the elf on its line
+    pub elf: usize,
+    pub sections: RangeInclusive<SectionId>,
+}
+
+/// Every assignment in the input, in input order
+pub fn assignments(crews: &[Crew]) -> Vec<Assignment> {
+    crews
+        .iter()
+        .enumerate()
+        .flat_map(|(index, crew)| {
+            crew.iter()
+                .enumerate()
+                .map(move |(elf, sections)| Assignment {
+                    line: index + 1,
+                    elf,
+                    sections: sections.clone(),
+                })
+        })
+        .collect()
+}
+
+/// How the first assignment of a conflict relates to the second one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Overlaps,
+    /// The first assignment fully contains the second one. Equal assignments contain each other
+    Contains,
+    ContainedBy,
+}
+
+/// Two assignments sharing at least one section. `first` comes before `second` in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    /// Index into the assignments
+    pub first: usize,
+    pub second: usize,
+    pub relation: Relation,
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Relation::*;
+
+        match self {
+            Overlaps => write!(f, "overlaps"),
+            Contains => write!(f, "contains"),
+            ContainedBy => write!(f, "is contained by"),
+        }
+    }
+}
+
+/// Finds every pair of overlapping assignments with a sweep over the section starts.
+/// Takes O(n log n + k log k) time for k conflicts, since they are sorted by the position of
+/// both assignments afterwards
+pub fn find_conflicts(assignments: &[Assignment]) -> Vec<Conflict> {
+    //Sorting longer ranges first makes every active range start no later than the current one,
+    //and end no earlier if they start together
+    let mut order = (0..assignments.len()).collect::<Vec<_>>();
+    order.sort_unstable_by_key(|&i| {
+        let sections = &assignments[i].sections;
+        (*sections.start(), std::cmp::Reverse(*sections.end()))
+    });
+
+    //Assignments that may still overlap upcoming ones, by their end
+    let mut active = BTreeSet::<(SectionId, usize)>::new();
+    let mut conflicts = vec![];
+
+    for current in order {
+        let sections = &assignments[current].sections;
+        if sections.is_empty() {
+            continue;
+        }
+
+        while let Some(&(end, _)) = active.first() {
+            if end >= *sections.start() {
+                break;
+            }
+            active.pop_first();
+        }
+
+        for &(end, other) in &active {
+            //`other` starts no later than `current`, so it contains it if it ends no earlier
+            let other_contains = end >= *sections.end();
+            let current_contains =
+                assignments[other].sections.start() == sections.start() && end <= *sections.end();
+
+            let (first, second, first_contains, second_contains) = if other < current {
+                (other, current, other_contains, current_contains)
+            } else {
+                (current, other, current_contains, other_contains)
+            };
+
+            let relation = match (first_contains, second_contains) {
+                (true, _) => Relation::Contains,
+                (false, true) => Relation::ContainedBy,
+                (false, false) => Relation::Overlaps,
+            };
+            conflicts.push(Conflict {
+                first,
+                second,
+                relation,
+            });
+        }
+
+        active.insert((*sections.end(), current));
+    }
+
+    conflicts.sort_unstable_by_key(|conflict| (conflict.first, conflict.second));
+    conflicts
+}
@@ -0,0 +1,229 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+/// Integer types whose inclusive ranges can be merged and measured
+pub trait Section: Copy + Ord + Display {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The next larger value, `None` for `MAX`
+    fn next(self) -> Option<Self>;
+
+    /// The next smaller value, `None` for `MIN`
+    fn prev(self) -> Option<Self>;
+
+    /// The number of values in `start..=end`
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! section {
+    ($($t:ty),*) => {
+        $(
+            impl Section for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+section!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// A set of sections, stored as sorted, disjoint ranges. Overlapping or adjacent ranges are merged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Section> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The merged ranges, in ascending order
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of sections in the set
+    pub fn covered(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|range| T::count(*range.start(), *range.end()))
+            .sum()
+    }
+
+    /// The smallest range containing the whole set
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+
+        Some(*first.start()..=*last.end())
+    }
+
+    pub fn contains(&self, section: T) -> bool {
+        let index = self.ranges.partition_point(|range| *range.end() < section);
+
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.contains(&section))
+    }
+
+    /// Adds a range, merging it with every range it overlaps or touches. Empty ranges are ignored
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        //Ranges ending right before `start` still get merged, since they touch
+        let first = self
+            .ranges
+            .partition_point(|r| r.end().next().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|r| end.next().is_none_or(|next| *r.start() <= next));
+
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = *x.start().max(y.start());
+            let end = *x.end().min(y.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            //Drop whichever range ends first, the other one may still overlap the next range
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = Some(*range.start());
+            let end = *range.end();
+
+            while let (Some(from), Some(cut)) = (start, others.peek()) {
+                if *cut.end() < from {
+                    others.next();
+                    continue;
+                }
+                if *cut.start() > end {
+                    break;
+                }
+
+                if *cut.start() > from {
+                    ranges.push(from..=cut.start().prev().unwrap());
+                }
+                start = cut.end().next().filter(|&next| next <= end);
+                if start.is_some() {
+                    others.next();
+                }
+            }
+
+            if let Some(start) = start {
+                ranges.push(start..=end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The sections within `bounds` that are not in the set
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> Self {
+        Self::from_iter([bounds]).difference(self)
+    }
+}
+
+impl<T: Section> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut sorted = iter
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<_>>();
+        sorted.sort_unstable_by_key(|range| *range.start());
+
+        let mut ranges = Vec::<RangeInclusive<T>>::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if last.end().next().is_none_or(|next| *range.start() <= next) => {
+                    *last = *last.start()..=*last.end().max(range.end());
+                }
+                _ => ranges.push(range),
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Section> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Section> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            if range.start() == range.end() {
+                write!(f, "{}", range.start())?;
+            } else {
+                write!(f, "{}-{}", range.start(), range.end())?;
+            }
+        }
+        Ok(())
+    }
+}
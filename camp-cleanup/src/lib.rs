@@ -0,0 +1,163 @@
+mod conflicts;
+mod interval_set;
+mod range;
+
+use std::ops::RangeInclusive;
+
+use aoc_common::{stream::StreamingSolution, ParseError, Solution};
+use log::debug;
+
+pub use conflicts::{assignments, find_conflicts, Assignment, Conflict, Relation};
+pub use interval_set::{IntervalSet, Section};
+pub use range::{parse_range, Reversed};
+
+/// A section number. Sections may be negative
+pub type SectionId = i64;
+
+/// The assignments of every elf on one line
+pub type Crew = Vec<RangeInclusive<SectionId>>;
+
+//A crew of one elf is fine, nothing is contained and the shared sections are its own range
+fn parse_crew(index: usize, line: &str, reversed: Reversed) -> Result<Crew, ParseError> {
+    line.split(',')
+        .map(|range| parse_range(index, line, range, reversed))
+        .collect()
+}
+
+/// Whether any assignment of the crew fully contains another one
+pub fn any_contains(crew: &[RangeInclusive<SectionId>]) -> bool {
+    let mut sorted = crew
+        .iter()
+        .filter(|range| !range.is_empty())
+        .collect::<Vec<_>>();
+    sorted.sort_unstable_by_key(|range| (*range.start(), std::cmp::Reverse(*range.end())));
+
+    //Every earlier range starts no later, so it contains this one if it also ends no earlier
+    let mut max_end = None;
+    sorted.into_iter().any(|range| {
+        let contained = max_end.is_some_and(|end| end >= *range.end());
+        max_end = max_end.max(Some(*range.end()));
+        contained
+    })
+}
+
+/// The sections every assignment of the crew shares, if there are any
+pub fn common_sections(crew: &[RangeInclusive<SectionId>]) -> Option<RangeInclusive<SectionId>> {
+    let start = crew.iter().map(|range| *range.start()).max()?;
+    let end = crew.iter().map(|range| *range.end()).min()?;
+
+    (start <= end).then_some(start..=end)
+}
+
+//A lone elf overlaps nobody, even though its own range is what it shares
+fn overlapping(crew: &[RangeInclusive<SectionId>]) -> bool {
+    crew.len() >= 2 && common_sections(crew).is_some()
+}
+
+/// The per-line results for one crew
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrewReport {
+    /// 1-based line number
+    pub line: usize,
+    pub any_contains: bool,
+    pub common: Option<RangeInclusive<SectionId>>,
+    pub coverage: IntervalSet<SectionId>,
+}
+
+pub fn crew_reports(crews: &[Crew]) -> Vec<CrewReport> {
+    crews
+        .iter()
+        .enumerate()
+        .map(|(index, crew)| CrewReport {
+            line: index + 1,
+            any_contains: any_contains(crew),
+            common: common_sections(crew),
+            coverage: crew.iter().cloned().collect(),
+        })
+        .collect()
+}
+
+/// Which sections are cleaned by at least one elf, and which ones in between by no one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    pub covered: IntervalSet<SectionId>,
+    pub uncovered: IntervalSet<SectionId>,
+}
+
+pub fn coverage(crews: &[Crew]) -> Coverage {
+    let covered = crews.iter().flatten().cloned().collect::<IntervalSet<_>>();
+
+    let uncovered = match covered.span() {
+        Some(span) => covered.gaps(span),
+        None => IntervalSet::new(),
+    };
+
+    Coverage { covered, uncovered }
+}
+
+/// Parses one crew per line
+pub fn parse_crews(input: &str, reversed: Reversed) -> Result<Vec<Crew>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_crew(index, line, reversed))
+        .collect::<Result<Vec<_>, _>>()
+        .inspect(|crews| debug!("Parsed {} crews of assignments", crews.len()))
+}
+
+/// Day 4. Reversed ranges are swapped if `NORMALISE` is set and rejected otherwise
+pub struct CampCleanupWith<const NORMALISE: bool>;
+
+pub type CampCleanup = CampCleanupWith<false>;
+
+const fn reversed(normalise: bool) -> Reversed {
+    if normalise {
+        Reversed::Normalise
+    } else {
+        Reversed::Reject
+    }
+}
+
+impl<const NORMALISE: bool> Solution for CampCleanupWith<NORMALISE> {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Crew>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Crew>, ParseError> {
+        parse_crews(input, reversed(NORMALISE))
+    }
+
+    fn part1(crews: &Vec<Crew>) -> usize {
+        crews.iter().filter(|crew| any_contains(crew)).count()
+    }
+
+    //For pairs, sharing a section is the same as overlapping
+    fn part2(crews: &Vec<Crew>) -> usize {
+        crews.iter().filter(|crew| overlapping(crew)).count()
+    }
+}
+
+/// How many crews seen so far have an assignment containing another, or share a section
+#[derive(Debug, Default)]
+pub struct Counts {
+    contained: usize,
+    overlapping: usize,
+}
+
+impl<const NORMALISE: bool> StreamingSolution for CampCleanupWith<NORMALISE> {
+    type State = Counts;
+
+    fn feed(counts: &mut Counts, index: usize, line: &str) -> Result<(), ParseError> {
+        let crew = parse_crew(index, line, reversed(NORMALISE))?;
+
+        counts.contained += any_contains(&crew) as usize;
+        counts.overlapping += overlapping(&crew) as usize;
+        Ok(())
+    }
+
+    fn finish(counts: Counts) -> Result<(usize, usize), ParseError> {
+        Ok((counts.contained, counts.overlapping))
+    }
+}
@@ -1,55 +1,210 @@
 use std::{
     error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-    ops::RangeInclusive,
+    io::{self, BufWriter, Write},
+    process::ExitCode,
 };
 
-trait RangeExt {
-    fn contains_range(&self, other: &Self) -> bool;
+use aoc_common::{
+    cli::{self, DayArgs, StreamArgs},
+    input::InputSource,
+    output::Format,
+};
+use camp_cleanup::{
+    assignments, coverage, crew_reports, find_conflicts, parse_crews, Assignment, CampCleanupWith,
+    Conflict, Coverage, CrewReport, IntervalSet, Relation, Reversed, SectionId,
+};
+use clap::Parser;
+use serde_json::json;
+
+const DEFAULT_INPUT: &str = "./task.txt";
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: DayArgs,
+
+    #[command(flatten)]
+    streaming: StreamArgs,
+
+    /// Report which sections are cleaned by anyone and which ones by no one
+    #[arg(long, conflicts_with = "stream")]
+    coverage: bool,
+
+    /// List every pair of assignments, across all lines, that overlap or contain each other
+    #[arg(long, conflicts_with_all = ["stream", "coverage"])]
+    conflicts: bool,
 
-    fn overlaps(&self, other: &Self) -> bool;
+    /// Analyse each line's crew: whether one assignment contains another, which sections
+    /// all of them share and which ones they cover together
+    #[arg(long, conflicts_with_all = ["stream", "coverage", "conflicts"])]
+    crews: bool,
+
+    /// How to handle ranges whose start is after their end, like '7-3'
+    #[arg(long, value_enum, default_value_t)]
+    reversed: Reversed,
+}
+
+fn sections(set: &IntervalSet<SectionId>) -> Vec<[SectionId; 2]> {
+    set.ranges()
+        .iter()
+        .map(|range| [*range.start(), *range.end()])
+        .collect()
 }
 
-impl<I: PartialOrd> RangeExt for RangeInclusive<I> {
-    fn contains_range(&self, other: &Self) -> bool {
-        self.contains(&other.start()) & self.contains(&other.end())
+fn show(set: &IntervalSet<SectionId>) -> String {
+    if set.is_empty() {
+        "none".to_owned()
+    } else {
+        set.to_string()
     }
+}
+
+fn print_coverage(format: Format, coverage: &Coverage) {
+    let Coverage { covered, uncovered } = coverage;
 
-    fn overlaps(&self, other: &Self) -> bool {
-        self.contains(other.start())
-            | self.contains(other.end())
-            | other.contains(self.start())
-            | other.contains(self.end())
+    match format {
+        Format::Text => {
+            for (name, set) in [("anyone", covered), ("no one", uncovered)] {
+                println!(
+                    "Covered by {name} ({} sections): {}",
+                    set.covered(),
+                    show(set)
+                );
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            json!({
+                "covered": sections(covered),
+                "covered_sections": covered.covered() as u64,
+                "uncovered": sections(uncovered),
+                "uncovered_sections": uncovered.covered() as u64,
+            })
+        ),
     }
 }
 
-fn parse_to_range(str: &str) -> Option<RangeInclusive<u32>> {
-    let [start, end] = str.split('-').collect::<Vec<_>>()[..2] else { return None };
+//There can be millions of conflicts, so they go through one buffered, locked handle
+fn print_conflicts(
+    format: Format,
+    assignments: &[Assignment],
+    conflicts: &[Conflict],
+) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
 
-    let start = start.parse().ok()?;
-    let end = end.parse().ok()?;
+    for conflict in conflicts {
+        let first = &assignments[conflict.first];
+        let second = &assignments[conflict.second];
 
-    Some(start..=end)
-}
+        match format {
+            Format::Text => writeln!(
+                out,
+                "line {} elf {} ({}-{}) {} line {} elf {} ({}-{})",
+                first.line,
+                first.elf + 1,
+                first.sections.start(),
+                first.sections.end(),
+                conflict.relation,
+                second.line,
+                second.elf + 1,
+                second.sections.start(),
+                second.sections.end(),
+            )?,
+            Format::Json => {
+                let relation = match conflict.relation {
+                    Relation::Overlaps => "overlaps",
+                    Relation::Contains => "contains",
+                    Relation::ContainedBy => "contained_by",
+                };
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = BufReader::new(File::open("./task.txt")?);
+                writeln!(
+                    out,
+                    "{}",
+                    json!({
+                        "first": { "line": first.line, "elf": first.elf + 1 },
+                        "second": { "line": second.line, "elf": second.elf + 1 },
+                        "relation": relation,
+                    })
+                )?
+            }
+        }
+    }
 
-    let pairs = file.lines().flatten().map(|line|{
-        let [first, second] = &line.split(',').flat_map(parse_to_range).collect::<Vec<_>>()[..2] else { unreachable!() };
+    if format == Format::Text {
+        writeln!(out, "{} conflicting pairs of assignments", conflicts.len())?;
+    }
+    out.flush()
+}
 
-        (first.clone(), second.clone())
-    }).collect::<Vec<_>>();
+fn print_crews(format: Format, reports: &[CrewReport]) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
 
-    let num_fully_overlapping_pairs = pairs
-        .iter()
-        .filter(|(a, b)| a.contains_range(&b) | b.contains_range(&a))
-        .count();
+    for report in reports {
+        match format {
+            Format::Text => {
+                let common = match &report.common {
+                    Some(common) => format!("{}-{}", common.start(), common.end()),
+                    None => "none".to_owned(),
+                };
 
-    let num_overlapping_pairs = pairs.iter().filter(|(a, b)| a.overlaps(&b)).count();
+                writeln!(
+                    out,
+                    "line {}: contains {}, shared {common}, covers {} sections ({})",
+                    report.line,
+                    if report.any_contains { "yes" } else { "no" },
+                    report.coverage.covered(),
+                    show(&report.coverage),
+                )?
+            }
+            Format::Json => writeln!(
+                out,
+                "{}",
+                json!({
+                    "line": report.line,
+                    "any_contains": report.any_contains,
+                    "common": report.common.as_ref().map(|common| [*common.start(), *common.end()]),
+                    "coverage": sections(&report.coverage),
+                    "covered_sections": report.coverage.covered() as u64,
+                })
+            )?,
+        }
+    }
+    out.flush()
+}
 
-    println!("{num_fully_overlapping_pairs} {num_overlapping_pairs}");
+fn report(source: &InputSource, cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let crews = parse_crews(&source.read()?, cli.reversed).map_err(|e| e.with_file(source))?;
+    let format = cli.args.format;
 
+    if cli.conflicts {
+        let assignments = assignments(&crews);
+        print_conflicts(format, &assignments, &find_conflicts(&assignments))?;
+    } else if cli.crews {
+        print_crews(format, &crew_reports(&crews))?;
+    } else {
+        print_coverage(format, &coverage(&crews));
+    }
     Ok(())
 }
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if !cli.coverage && !cli.conflicts && !cli.crews {
+        return match cli.reversed {
+            Reversed::Reject => cli::run_streaming::<CampCleanupWith<false>>(
+                &cli.args,
+                cli.streaming.stream,
+                DEFAULT_INPUT,
+            ),
+            Reversed::Normalise => cli::run_streaming::<CampCleanupWith<true>>(
+                &cli.args,
+                cli.streaming.stream,
+                DEFAULT_INPUT,
+            ),
+        };
+    }
+
+    let source = cli.args.init(DEFAULT_INPUT);
+    cli::exit_code(report(&source, &cli))
+}
@@ -0,0 +1,78 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::ParseError;
+use clap::ValueEnum;
+
+use crate::SectionId;
+
+/// What to do with ranges like `7-3`, whose start is after their end
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Reversed {
+    /// Report them as an error
+    #[default]
+    Reject,
+    /// Swap the bounds, so `7-3` means `3-7`
+    Normalise,
+}
+
+/// Splits a leading bound, an optional `-` followed by digits, off `token`
+fn split_bound(token: &str) -> (&str, &str) {
+    let sign = usize::from(token.starts_with('-'));
+    let end = token[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(token.len(), |i| i + sign);
+
+    token.split_at(end)
+}
+
+/// Parses a range like `2-4`, `-5-3` or `-5--3`. Errors point at the exact token that is wrong
+pub fn parse_range(
+    line_index: usize,
+    line: &str,
+    token: &str,
+    reversed: Reversed,
+) -> Result<RangeInclusive<SectionId>, ParseError> {
+    let error =
+        |token: &str, message: String| ParseError::at_token(line_index, line, token, message);
+
+    let parse_bound = |bound: &str, rest: &str| {
+        if bound.trim_start_matches('-').is_empty() {
+            //Point at whatever is there instead of a number
+            let found = if rest.is_empty() { bound } else { rest };
+            return Err(error(found, "Expected a section number".to_owned()));
+        }
+
+        bound
+            .parse::<SectionId>()
+            .map_err(|e| error(bound, format!("Invalid section: {e}")))
+    };
+
+    let (start, rest) = split_bound(token);
+    let start = parse_bound(start, rest)?;
+
+    let Some(rest) = rest.strip_prefix('-') else {
+        let found = if rest.is_empty() { token } else { rest };
+        return Err(error(found, "Expected a range like '2-4'".to_owned()));
+    };
+
+    let (end, trailing) = split_bound(rest);
+    let end = parse_bound(end, trailing)?;
+
+    if !trailing.is_empty() {
+        return Err(error(
+            trailing,
+            format!("Unexpected '{trailing}' after the range"),
+        ));
+    }
+
+    match reversed {
+        _ if start <= end => Ok(start..=end),
+        Reversed::Normalise => Ok(end..=start),
+        Reversed::Reject => Err(error(
+            token,
+            format!(
+                "Reversed range, {start} is after {end} (pass --reversed normalise to swap them)"
+            ),
+        )),
+    }
+}
@@ -0,0 +1,84 @@
+use aoc_common::Solution;
+use camp_cleanup::{assignments, find_conflicts, Assignment, CampCleanup, Conflict, Relation};
+
+//The straightforward O(n²) check the sweep has to agree with
+fn brute_force(assignments: &[Assignment]) -> Vec<Conflict> {
+    let mut conflicts = vec![];
+
+    for (first, a) in assignments.iter().enumerate() {
+        for (offset, b) in assignments[first + 1..].iter().enumerate() {
+            let (a, b) = (&a.sections, &b.sections);
+            if a.start() > b.end() || b.start() > a.end() {
+                continue;
+            }
+
+            let relation = if a.start() <= b.start() && b.end() <= a.end() {
+                Relation::Contains
+            } else if b.start() <= a.start() && a.end() <= b.end() {
+                Relation::ContainedBy
+            } else {
+                Relation::Overlaps
+            };
+            conflicts.push(Conflict {
+                first,
+                second: first + 1 + offset,
+                relation,
+            });
+        }
+    }
+
+    conflicts
+}
+
+#[test]
+fn finds_conflicts_across_lines() {
+    let pairs = CampCleanup::parse("1-3,10-12\n2-2,20-30\n11-25,40-41\n").unwrap();
+    let assignments = assignments(&pairs);
+
+    assert_eq!(assignments[4].line, 3);
+    assert_eq!(assignments[4].elf, 0);
+    assert_eq!(
+        find_conflicts(&assignments),
+        [
+            Conflict {
+                first: 0,
+                second: 2,
+                relation: Relation::Contains
+            },
+            Conflict {
+                first: 1,
+                second: 4,
+                relation: Relation::Overlaps
+            },
+            Conflict {
+                first: 3,
+                second: 4,
+                relation: Relation::Overlaps
+            },
+        ]
+    );
+}
+
+#[test]
+fn equal_and_nested_assignments() {
+    let pairs = CampCleanup::parse("4-6,4-6\n4-9,5-5\n").unwrap();
+    let conflicts = find_conflicts(&assignments(&pairs));
+
+    assert_eq!(conflicts.len(), 6);
+    assert_eq!(conflicts[0].relation, Relation::Contains);
+    assert_eq!(conflicts[1].relation, Relation::ContainedBy);
+}
+
+#[test]
+fn matches_brute_force_on_the_example() {
+    let assignments = assignments(&CampCleanup::parse(include_str!("../test.txt")).unwrap());
+
+    assert_eq!(find_conflicts(&assignments), brute_force(&assignments));
+}
+
+#[test]
+fn matches_brute_force_on_the_task() {
+    let assignments = assignments(&CampCleanup::parse(include_str!("../task.txt")).unwrap());
+
+    assert_eq!(find_conflicts(&assignments), brute_force(&assignments));
+}
@@ -0,0 +1,56 @@
+use aoc_common::{stream::solve_stream, Solution};
+use camp_cleanup::{any_contains, common_sections, crew_reports, CampCleanup};
+
+#[test]
+fn accepts_any_number_of_ranges() {
+    let crews = CampCleanup::parse("2-4,6-8,3-3\n1-9,2-5,4-7,5-5\n1-2,3-4\n").unwrap();
+
+    assert_eq!(crews.iter().map(Vec::len).collect::<Vec<_>>(), [3, 4, 2]);
+    assert_eq!(CampCleanup::part1(&crews), 2);
+    assert_eq!(CampCleanup::part2(&crews), 1);
+}
+
+#[test]
+fn accepts_single_ranges() {
+    let input = "2-4,6-8\n2-3\n";
+    let crews = CampCleanup::parse(input).unwrap();
+
+    assert_eq!(crews[1], [2..=3]);
+    assert!(!any_contains(&crews[1]));
+    assert_eq!(common_sections(&crews[1]), Some(2..=3));
+    assert_eq!(CampCleanup::part1(&crews), 0);
+    //A lone elf overlaps nobody, only the pair could and it does not
+    assert_eq!(CampCleanup::part2(&crews), 0);
+    assert_eq!(
+        solve_stream::<CampCleanup>(input.as_bytes()).unwrap(),
+        (0, 0)
+    );
+}
+
+#[test]
+fn containment_within_a_crew() {
+    assert!(any_contains(&[5..=6, 1..=3, 4..=8]));
+    assert!(any_contains(&[2..=3, 2..=3]));
+    assert!(any_contains(&[1..=4, 6..=9, 1..=5]));
+    assert!(!any_contains(&[1..=4, 3..=6, 5..=8]));
+}
+
+#[test]
+fn sections_shared_by_the_whole_crew() {
+    assert_eq!(common_sections(&[1..=6, 4..=9, 3..=5]), Some(4..=5));
+    assert_eq!(common_sections(&[1..=3, 3..=5, 2..=8]), Some(3..=3));
+    assert_eq!(common_sections(&[1..=3, 2..=5, 4..=8]), None);
+}
+
+#[test]
+fn reports_every_line() {
+    let crews = CampCleanup::parse("2-4,6-8,3-3\n1-9,2-5,4-7\n").unwrap();
+    let reports = crew_reports(&crews);
+
+    assert_eq!(reports[0].line, 1);
+    assert!(reports[0].any_contains);
+    assert_eq!(reports[0].common, None);
+    assert_eq!(reports[0].coverage.ranges(), [2..=4, 6..=8]);
+    assert_eq!(reports[1].common, Some(4..=5));
+    assert_eq!(reports[1].coverage.covered(), 9);
+}
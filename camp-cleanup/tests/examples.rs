@@ -0,0 +1,7 @@
+use camp_cleanup::CampCleanup;
+
+aoc_common::examples! {
+    CampCleanup;
+    example: "test.txt" => (Some(2), Some(4)),
+    task: "task.txt" => (Some(602), Some(891)),
+}
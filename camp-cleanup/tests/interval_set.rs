@@ -0,0 +1,80 @@
+use aoc_common::Solution;
+use camp_cleanup::{coverage, CampCleanup, IntervalSet};
+
+fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+    ranges.iter().map(|&(start, end)| start..=end).collect()
+}
+
+#[test]
+fn merges_overlapping_and_adjacent_ranges() {
+    let merged = set(&[(5, 7), (1, 2), (3, 3), (6, 9), (12, 14)]);
+
+    assert_eq!(merged.ranges(), [1..=3, 5..=9, 12..=14]);
+    assert_eq!(merged.covered(), 11);
+    assert_eq!(merged.to_string(), "1-3, 5-9, 12-14");
+}
+
+#[test]
+fn insert_matches_collecting() {
+    let ranges = [(10, 12), (1, 3), (5, 5), (4, 4), (7, 8), (0, 20), (30, 31)];
+
+    let mut inserted = IntervalSet::new();
+    for &(start, end) in &ranges {
+        inserted.insert(start..=end);
+    }
+
+    assert_eq!(inserted, set(&ranges));
+    assert_eq!(inserted.ranges(), [0..=20, 30..=31]);
+}
+
+#[test]
+fn ignores_empty_ranges_and_handles_extremes() {
+    let mut extremes = IntervalSet::new();
+    let (start, end) = (5, 4);
+    extremes.insert(start..=end);
+    assert!(extremes.is_empty());
+
+    extremes.insert(u8::MAX - 1..=u8::MAX);
+    extremes.insert(0..=0);
+    extremes.insert(1..=u8::MAX - 2);
+    assert_eq!(extremes.ranges(), [0..=u8::MAX]);
+    assert_eq!(extremes.covered(), 256);
+}
+
+#[test]
+fn point_queries() {
+    let sections = set(&[(1, 3), (7, 9)]);
+
+    assert!(sections.contains(1));
+    assert!(sections.contains(9));
+    assert!(!sections.contains(5));
+    assert!(!sections.contains(10));
+}
+
+#[test]
+fn set_operations() {
+    let a = set(&[(1, 5), (10, 15)]);
+    let b = set(&[(4, 11), (14, 20)]);
+
+    assert_eq!(a.union(&b).ranges(), [1..=20]);
+    assert_eq!(a.intersection(&b).ranges(), [4..=5, 10..=11, 14..=15]);
+    assert_eq!(a.difference(&b).ranges(), [1..=3, 12..=13]);
+    assert_eq!(b.difference(&a).ranges(), [6..=9, 16..=20]);
+    assert!(a.difference(&a).is_empty());
+}
+
+#[test]
+fn gaps_within_bounds() {
+    let sections = set(&[(-3, -1), (2, 4)]);
+
+    assert_eq!(sections.gaps(-5..=6).ranges(), [-5..=-4, 0..=1, 5..=6]);
+}
+
+#[test]
+fn reports_coverage_across_the_input() {
+    let pairs = CampCleanup::parse("2-4,6-8\n12-13,3-5\n").unwrap();
+    let coverage = coverage(&pairs);
+
+    assert_eq!(coverage.covered.ranges(), [2..=8, 12..=13]);
+    assert_eq!(coverage.uncovered.ranges(), [9..=11]);
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use camp_cleanup::CampCleanup;
+
+#[test]
+fn rejects_missing_range() {
+    let error = CampCleanup::parse("2-4,6-8\n2-3,\n").unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (2, 5));
+}
+
+#[test]
+fn points_at_bad_section() {
+    let error = CampCleanup::parse("2-4,6-x\n").unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (1, 7));
+}
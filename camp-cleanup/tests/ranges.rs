@@ -0,0 +1,45 @@
+use aoc_common::Solution;
+use camp_cleanup::{parse_range, CampCleanup, CampCleanupWith, Reversed};
+
+fn range(token: &str) -> Result<std::ops::RangeInclusive<i64>, (usize, String)> {
+    parse_range(0, token, token, Reversed::Reject).map_err(|e| (e.column(), e.message().to_owned()))
+}
+
+#[test]
+fn negative_bounds() {
+    assert_eq!(range("-5-3"), Ok(-5..=3));
+    assert_eq!(range("-5--3"), Ok(-5..=-3));
+    assert_eq!(range("0-0"), Ok(0..=0));
+}
+
+#[test]
+fn reversed_ranges_are_rejected_or_swapped() {
+    let (column, message) = range("7-3").unwrap_err();
+    assert_eq!(column, 1);
+    assert!(message.contains("Reversed"), "{message}");
+
+    assert_eq!(
+        parse_range(0, "3--2", "3--2", Reversed::Normalise),
+        Ok(-2..=3)
+    );
+
+    let crews = CampCleanupWith::<true>::parse("2-8,7-3\n").unwrap();
+    assert_eq!(crews[0][1], 3..=7);
+    assert_eq!(CampCleanupWith::<true>::part1(&crews), 1);
+    assert!(CampCleanup::parse("2-8,7-3\n").is_err());
+}
+
+#[test]
+fn errors_point_at_the_malformed_token() {
+    assert_eq!(range("2-x").unwrap_err().0, 3);
+    assert_eq!(range("2-4x").unwrap_err().0, 4);
+    assert_eq!(range("x-4").unwrap_err().0, 1);
+    assert_eq!(range("-").unwrap_err().0, 1);
+
+    let (column, message) = range("12").unwrap_err();
+    assert_eq!(column, 1);
+    assert!(message.contains("'2-4'"), "{message}");
+
+    let error = CampCleanup::parse("1-2,3-4\n1-2,5-6,+7-8\n").unwrap_err();
+    assert_eq!((error.line(), error.column()), (2, 9));
+}
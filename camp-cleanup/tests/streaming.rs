@@ -0,0 +1,12 @@
+use aoc_common::testing::check_stream;
+use camp_cleanup::CampCleanup;
+
+#[test]
+fn example() {
+    check_stream::<CampCleanup>(include_str!("../test.txt"));
+}
+
+#[test]
+fn task() {
+    check_stream::<CampCleanup>(include_str!("../task.txt"));
+}
@@ -0,0 +1,199 @@
+//Hint: This can be done much smarter by just counting cycles. No need to do this but yolo
+
+use std::collections::VecDeque;
+
+use aoc_common::{ParseError, Solution};
+use log::trace;
+
+const SCREEN_WIDTH: i32 = 40;
+const SCREEN_HEIGHT: i32 = 6;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    NoOp,
+    AddX(i32),
+}
+
+impl Instruction {
+    fn from_line(index: usize, line: &str) -> Result<Self, ParseError> {
+        use Instruction::*;
+
+        if line == "noop" {
+            return Ok(NoOp);
+        }
+
+        let Some(value) = line.strip_prefix("addx ") else {
+            return Err(ParseError::at_line(index, line, "Unknown instruction"));
+        };
+
+        let value = value.parse().map_err(|e| {
+            ParseError::at_token(index, line, value, format!("Invalid addx value: {e}"))
+        })?;
+
+        Ok(AddX(value))
+    }
+}
+
+struct Cpu {
+    cycle: u32,
+    occupied_until: u32,
+    x: i32,
+    crt_x: i32,
+    screen: String,
+
+    current_instruction: Option<Instruction>,
+    instructions: VecDeque<Instruction>,
+}
+
+#[derive(PartialEq)]
+enum IsDone {
+    Yes,
+    No,
+}
+
+impl Cpu {
+    fn new(instructions: VecDeque<Instruction>) -> Self {
+        Self {
+            cycle: 1,
+            occupied_until: 0,
+            x: 1,
+            crt_x: 0,
+            screen: String::new(),
+            current_instruction: None,
+            instructions,
+        }
+    }
+
+    fn should_yield(&self) -> bool {
+        if self.cycle < 20 {
+            return false;
+        }
+
+        (self.cycle - 20).is_multiple_of(40)
+    }
+
+    fn part1(&self) -> i32 {
+        if !self.should_yield() {
+            return 0;
+        }
+
+        let signal_strength = (self.cycle as i32) * self.x;
+        trace!(
+            "Signal strength during cycle {} is {signal_strength}",
+            self.cycle
+        );
+
+        signal_strength
+    }
+
+    fn part2(&mut self) {
+        //The last instruction may finish after the screen is full
+        if self.cycle as i32 > SCREEN_WIDTH * SCREEN_HEIGHT {
+            return;
+        }
+
+        if self.crt_x.abs_diff(self.x) <= 1 {
+            self.screen.push('#');
+        } else {
+            self.screen.push('.');
+        }
+    }
+
+    fn fetch_next_instruction(&mut self) {
+        use Instruction::*;
+
+        if self.cycle < self.occupied_until {
+            return;
+        }
+
+        self.current_instruction = match self.instructions.pop_front() {
+            None => None,
+            Some(NoOp) => {
+                self.occupied_until = self.cycle + 1;
+                Some(NoOp)
+            }
+            Some(AddX(value)) => {
+                self.occupied_until = self.cycle + 2;
+                Some(AddX(value))
+            }
+        }
+    }
+
+    fn execute_current_instruction(&mut self) -> IsDone {
+        use Instruction::*;
+
+        if self.cycle < self.occupied_until {
+            return IsDone::No;
+        }
+
+        match self.current_instruction {
+            None => IsDone::Yes,
+            Some(NoOp) => IsDone::No,
+            Some(AddX(v)) => {
+                self.x += v;
+                IsDone::No
+            }
+        }
+    }
+
+    fn update_crt(&mut self) {
+        self.crt_x += 1;
+        if self.crt_x == SCREEN_WIDTH {
+            self.screen.push('\n');
+            self.crt_x = 0;
+        }
+    }
+
+    fn run(&mut self) -> i32 {
+        let mut result = 0;
+
+        loop {
+            //During cycle
+            result += self.part1();
+            self.part2();
+
+            self.fetch_next_instruction();
+
+            //End of cycle
+            self.cycle += 1;
+            self.update_crt();
+
+            //After cycle
+            if self.execute_current_instruction() == IsDone::Yes {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+pub struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Instruction::from_line(index, line))
+            .collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> i32 {
+        let mut cpu = Cpu::new(instructions.iter().copied().collect());
+        cpu.run()
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> String {
+        let mut cpu = Cpu::new(instructions.iter().copied().collect());
+        cpu.run();
+
+        cpu.screen
+    }
+}
@@ -0,0 +1,26 @@
+use cathode_ray_tube::CathodeRayTube;
+
+const LARGER_EXAMPLE_SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+
+const TASK_SCREEN: &str = "\
+####..##...##....##.####...##.####.#....
+...#.#..#.#..#....#....#....#.#....#....
+..#..#....#.......#...#.....#.###..#....
+.#...#.##.#.......#..#......#.#....#....
+#....#..#.#..#.#..#.#....#..#.#....#....
+####..###..##...##..####..##..#....####.
+";
+
+aoc_common::examples! {
+    CathodeRayTube;
+    example: "test.txt" => (Some(0), None),
+    larger_example: "test2.txt" => (Some(13140), Some(LARGER_EXAMPLE_SCREEN.into())),
+    task: "task.txt" => (Some(14040), Some(TASK_SCREEN.into())),
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use cathode_ray_tube::CathodeRayTube;
+
+#[test]
+fn rejects_unknown_instruction() {
+    let error = CathodeRayTube::parse("noop\nmul 3\n").unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (2, 1));
+}
+
+#[test]
+fn points_at_bad_addx_value() {
+    let error = CathodeRayTube::parse("addx 1\naddx x\n").unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (2, 6));
+}
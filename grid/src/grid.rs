@@ -0,0 +1,278 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{Direction, Point};
+
+/// A dense, rectangular grid stored row by row in a single `Vec`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseError {
+    Empty,
+    /// The character at `at` (counted in characters, not bytes) has no cell value
+    InvalidCell {
+        at: Point,
+        found: char,
+    },
+    /// A row does not have the same width as the first one
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use GridParseError::*;
+
+        match self {
+            Empty => write!(f, "The grid is empty"),
+            InvalidCell { at, found } => {
+                write!(f, "Invalid cell '{found}' at x={}, y={}", at.x, at.y)
+            }
+            RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(f, "Row {row} has {found} cells, expected {expected}"),
+        }
+    }
+}
+
+impl Error for GridParseError {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from rows which all need to have the same, non-zero length
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, GridParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for row in rows {
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(GridParseError::RaggedRow {
+                    row: height,
+                    expected,
+                    found: row.len(),
+                });
+            }
+
+            cells.extend(row);
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(GridParseError::Empty),
+        }
+    }
+
+    /// Parses a character map, one row per line. `cell` returns `None` for characters that are not allowed
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridParseError> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        cell(c).ok_or(GridParseError::InvalidCell {
+                            at: Point::new(x as i32, y as i32),
+                            found: c,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(self.index_of(point)?)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        let index = self.index_of(point)?;
+        self.cells.get_mut(index)
+    }
+
+    /// All cells, row by row
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// All points in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Grids without cells have no rows, even if only their width is 0
+    pub fn rows(&self) -> slice::ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.rows().nth(y)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = match self.cells.get(x..) {
+            Some(cells) if x < self.width => cells,
+            _ => &[],
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Walks from `from` (exclusive) in `direction` until the edge of the grid
+    pub fn ray(&self, from: Point, direction: Direction) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            current: from,
+            step: direction.offset(),
+        }
+    }
+
+    /// The orthogonally adjacent cells that are inside the grid
+    pub fn neighbors_4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbors_4()
+            .filter_map(|neighbor| Some((neighbor, self.get(neighbor)?)))
+    }
+
+    /// All surrounding cells, including diagonals, that are inside the grid
+    pub fn neighbors_8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbors_8()
+            .filter_map(|neighbor| Some((neighbor, self.get(neighbor)?)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the cells in one direction, see [`Grid::ray`]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    current: Point,
+    step: Point,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.current + self.step;
+        let cell = self.grid.get(next)?;
+
+        self.current = next;
+        Some((next, cell))
+    }
+}
@@ -0,0 +1,7 @@
+//! Dense 2D grids and integer points, shared by the grid based puzzles
+
+mod grid;
+mod point;
+
+pub use grid::{Grid, GridParseError, Ray};
+pub use point::{Direction, Point};
@@ -0,0 +1,143 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position (or offset) on a grid. `y` grows downwards, like the rows of the input
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    /// Offsets to the 4 orthogonally adjacent points
+    pub const NEIGHBORS_4: [Point; 4] = [
+        Point::new(0, -1),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(-1, 0),
+    ];
+
+    /// Offsets to all 8 surrounding points, including diagonals
+    pub const NEIGHBORS_8: [Point; 8] = [
+        Point::new(-1, -1),
+        Point::new(0, -1),
+        Point::new(1, -1),
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(-1, 1),
+        Point::new(-1, 0),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Point::new(i32::min(self.x, other.x), i32::min(self.y, other.y))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Point::new(i32::max(self.x, other.x), i32::max(self.y, other.y))
+    }
+
+    /// The offset of at most one step per axis towards the same direction as `self`
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of king moves between the points, so adjacent points (including diagonals) are 1 apart
+    pub fn chebyshev_distance(&self, other: &Self) -> u32 {
+        u32::max(self.x.abs_diff(other.x), self.y.abs_diff(other.y))
+    }
+
+    pub fn neighbors_4(self) -> impl Iterator<Item = Point> {
+        Point::NEIGHBORS_4
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+
+    pub fn neighbors_8(self) -> impl Iterator<Item = Point> {
+        Point::NEIGHBORS_8
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The offset of a single step in this direction
+    pub fn offset(&self) -> Point {
+        use Direction::*;
+
+        match self {
+            Up => Point::new(0, -1),
+            Right => Point::new(1, 0),
+            Down => Point::new(0, 1),
+            Left => Point::new(-1, 0),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use grid::{Direction, Grid, GridParseError, Point};
+
+fn digits(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn parses_character_maps() {
+    let grid = digits("123\n456\n");
+
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid[Point::new(0, 0)], 1);
+    assert_eq!(grid[Point::new(2, 1)], 6);
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, -1)), None);
+    assert_eq!(grid.to_string(), "123\n456\n");
+}
+
+#[test]
+fn rejects_invalid_maps() {
+    let parse = |input| Grid::parse(input, |c| c.to_digit(10));
+
+    assert_eq!(parse(""), Err(GridParseError::Empty));
+    assert_eq!(
+        parse("12\n3x"),
+        Err(GridParseError::InvalidCell {
+            at: Point::new(1, 1),
+            found: 'x'
+        })
+    );
+    assert_eq!(
+        parse("12\n345"),
+        Err(GridParseError::RaggedRow {
+            row: 1,
+            expected: 2,
+            found: 3
+        })
+    );
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = digits("123\n456\n789");
+
+    assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(grid.row(3), None);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+    assert_eq!(grid.column(3).count(), 0);
+    assert_eq!(grid.rows().count(), 3);
+}
+
+#[test]
+fn empty_grids() {
+    for grid in [
+        Grid::new(0, 3, 0u8),
+        Grid::new(3, 0, 0u8),
+        Grid::from_fn(0, 0, |_| 0u8),
+    ] {
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.row(0), None);
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.column(1).count(), 0);
+        assert_eq!(grid.to_string(), "");
+    }
+}
+
+#[test]
+fn rays_exclude_the_start() {
+    let grid = digits("123\n456\n789");
+    let ray = |direction| {
+        grid.ray(Point::new(1, 1), direction)
+            .map(|(_, &v)| v)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(ray(Direction::Up), [2]);
+    assert_eq!(ray(Direction::Right), [6]);
+    assert_eq!(ray(Direction::Down), [8]);
+    assert_eq!(ray(Direction::Left), [4]);
+    assert_eq!(grid.ray(Point::new(0, 0), Direction::Left).count(), 0);
+    assert_eq!(grid.ray(Point::new(0, 0), Direction::Down).count(), 2);
+}
+
+#[test]
+fn neighbors_stay_in_bounds() {
+    let grid = digits("123\n456\n789");
+
+    assert_eq!(grid.neighbors_4(Point::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbors_8(Point::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbors_8(Point::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn map_and_enumerate() {
+    let mut grid = Grid::new(2, 2, 0);
+    grid[Point::new(1, 0)] = 5;
+
+    let doubled = grid.map(|v| v * 2);
+    assert_eq!(doubled[Point::new(1, 0)], 10);
+
+    let points = doubled
+        .enumerate()
+        .filter(|(_, &v)| v > 0)
+        .map(|(p, _)| p)
+        .collect::<Vec<_>>();
+    assert_eq!(points, [Point::new(1, 0)]);
+
+    let from_fn = Grid::from_fn(3, 2, |p| p.x + p.y);
+    assert_eq!(from_fn.iter().sum::<i32>(), 9);
+}
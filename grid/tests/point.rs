@@ -0,0 +1,61 @@
+use grid::{Direction, Point};
+
+#[test]
+fn arithmetic() {
+    let a = Point::new(3, -2);
+    let b = Point::new(-1, 5);
+
+    assert_eq!(a + b, Point::new(2, 3));
+    assert_eq!(a - b, Point::new(4, -7));
+    assert_eq!(-a, Point::new(-3, 2));
+    assert_eq!(a * 3, Point::new(9, -6));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+}
+
+#[test]
+fn distances() {
+    let a = Point::new(1, 1);
+    let b = Point::new(4, -1);
+
+    assert_eq!(a.manhattan_distance(&b), 5);
+    assert_eq!(a.chebyshev_distance(&b), 3);
+    assert_eq!((b - a).signum(), Point::new(1, -1));
+}
+
+#[test]
+fn component_wise_bounds() {
+    let a = Point::new(1, 7);
+    let b = Point::new(4, -1);
+
+    assert_eq!(a.min(b), Point::new(1, -1));
+    assert_eq!(a.max(b), Point::new(4, 7));
+}
+
+#[test]
+fn neighbors() {
+    let center = Point::new(2, 2);
+
+    let four = center.neighbors_4().collect::<Vec<_>>();
+    assert_eq!(four.len(), 4);
+    assert!(four.iter().all(|p| p.manhattan_distance(&center) == 1));
+
+    let eight = center.neighbors_8().collect::<Vec<_>>();
+    assert_eq!(eight.len(), 8);
+    assert!(eight.iter().all(|p| p.chebyshev_distance(&center) == 1));
+}
+
+#[test]
+fn directions_point_down_the_rows() {
+    assert_eq!(Direction::Up.offset(), Point::new(0, -1));
+    assert_eq!(Direction::Down.offset(), Point::new(0, 1));
+    assert_eq!(
+        Direction::ALL
+            .iter()
+            .fold(Point::ORIGIN, |sum, d| sum + d.offset()),
+        Point::ORIGIN
+    );
+}
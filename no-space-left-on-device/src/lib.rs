@@ -0,0 +1,244 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{ParseError, Solution};
+use log::debug;
+
+const FILESYSTEM_SIZE: usize = 70_000_000;
+const UPDATE_SIZE: usize = 30_000_000;
+
+/// The remaining input lines together with their index, for error reporting
+type Lines<'a> = VecDeque<(usize, &'a str)>;
+
+pub enum DirectoryEntry {
+    File {
+        size: usize,
+    },
+    Directory {
+        entries: HashMap<String, DirectoryEntry>,
+    },
+}
+
+impl DirectoryEntry {
+    fn new_dir() -> Self {
+        use DirectoryEntry::Directory;
+        Directory {
+            entries: HashMap::default(),
+        }
+    }
+
+    fn insert_entries<T: IntoIterator<Item = (String, DirectoryEntry)>>(&mut self, to_insert: T) {
+        use DirectoryEntry::Directory;
+        let Directory { entries } = self else {
+            panic!("Tried to add entries to file!")
+        };
+
+        entries.extend(to_insert);
+    }
+
+    fn get_entry(&mut self, name: &str) -> Option<&mut DirectoryEntry> {
+        use DirectoryEntry::*;
+
+        let Directory { entries } = self else {
+            return None;
+        };
+
+        entries.get_mut(name)
+    }
+
+    fn get_size(&self) -> usize {
+        use DirectoryEntry::*;
+
+        match self {
+            File { size } => *size,
+            Directory { entries } => entries.values().map(|entry| entry.get_size()).sum(),
+        }
+    }
+
+    // PART 1
+    fn sum_size_below(&self, limit: usize) -> usize {
+        use DirectoryEntry::*;
+        match self {
+            File { size } if *size <= limit => *size,
+            Directory { entries } => {
+                let my_size = self.get_size();
+                let my_size = if my_size < limit { my_size } else { 0 };
+
+                let subdirectory_size = entries
+                    .values()
+                    .map(|entry| {
+                        if let Directory { .. } = entry {
+                            entry.sum_size_below(limit)
+                        } else {
+                            0
+                        }
+                    })
+                    .sum::<usize>();
+
+                my_size + subdirectory_size
+            }
+            _ => 0,
+        }
+    }
+
+    // PART 2
+    fn find_smallest_above(&self, minimum: usize) -> usize {
+        self._find_smallest_above(minimum, usize::MAX)
+    }
+
+    fn _find_smallest_above(&self, minimum: usize, current_best: usize) -> usize {
+        use DirectoryEntry::*;
+
+        let my_guess = match self {
+            File { size } => {
+                if *size >= minimum {
+                    *size
+                } else {
+                    current_best
+                }
+            }
+            Directory { entries } => {
+                let my_size = self.get_size();
+
+                //bail out early if we didn't make the cut
+                if my_size < minimum {
+                    return current_best;
+                }
+
+                let smallest_child = entries
+                    .values()
+                    .map(|entry| {
+                        if let Directory { .. } = entry {
+                            entry._find_smallest_above(minimum, current_best)
+                        } else {
+                            0
+                        }
+                    })
+                    .filter(|&guess| guess >= minimum)
+                    .min()
+                    .unwrap_or(current_best);
+
+                usize::min(my_size, smallest_child)
+            }
+        };
+
+        usize::min(my_guess, current_best)
+    }
+}
+
+fn parse_input(lines: &mut Lines) -> Result<DirectoryEntry, ParseError> {
+    use DirectoryEntry::*;
+
+    let mut current_directory = DirectoryEntry::new_dir();
+
+    while let Some((index, line)) = lines.pop_front() {
+        let Some(cmd) = line.strip_prefix("$ ") else {
+            return Err(ParseError::at_line(index, line, "Expected command"));
+        };
+
+        if cmd == "ls" {
+            let entries = parse_ls(lines)?;
+            current_directory.insert_entries(entries);
+        } else if let Some(target) = cmd.strip_prefix("cd ") {
+            //Go back up to the parent
+            if target == ".." {
+                return Ok(current_directory);
+            }
+
+            // Edit an existing entry
+            match current_directory.get_entry(target) {
+                Some(entry @ Directory { .. }) => *entry = parse_input(lines)?,
+                _ => {
+                    return Err(ParseError::at_token(
+                        index,
+                        line,
+                        target,
+                        "No such directory (run ls first)",
+                    ))
+                }
+            }
+        } else {
+            return Err(ParseError::at_token(index, line, cmd, "Unknown command"));
+        }
+    }
+
+    //All lines parsed :)
+    Ok(current_directory)
+}
+
+fn parse_ls(lines: &mut Lines) -> Result<Vec<(String, DirectoryEntry)>, ParseError> {
+    use DirectoryEntry::*;
+
+    let mut entries = vec![];
+
+    while let Some((index, line)) = lines.pop_front() {
+        if line.starts_with('$') {
+            //Command, put it back so our caller can keep parsing
+            lines.push_front((index, line));
+            break;
+        }
+
+        if let Some(name) = line.strip_prefix("dir ") {
+            //Directory
+            entries.push((name.into(), DirectoryEntry::new_dir()));
+        } else {
+            //File
+            let Some((size, name)) = line.split_once(' ') else {
+                return Err(ParseError::at_line(
+                    index,
+                    line,
+                    "Expected '<size> <name>' or 'dir <name>'",
+                ));
+            };
+            let size = size.parse().map_err(|e| {
+                ParseError::at_token(index, line, size, format!("Invalid file size: {e}"))
+            })?;
+
+            entries.push((name.into(), File { size }));
+        }
+    }
+
+    Ok(entries)
+}
+
+pub struct NoSpaceLeftOnDevice;
+
+impl Solution for NoSpaceLeftOnDevice {
+    const DAY: u8 = 7;
+
+    type Parsed = DirectoryEntry;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<DirectoryEntry, ParseError> {
+        let mut lines = input.lines().enumerate().collect::<Lines>();
+
+        match lines.pop_front() {
+            Some((_, "$ cd /")) => {}
+            Some((index, line)) => {
+                return Err(ParseError::at_line(index, line, "Expected '$ cd /'"))
+            }
+            None => return Err(ParseError::at_end(input, "Expected '$ cd /'")),
+        }
+
+        parse_input(&mut lines)
+    }
+
+    fn part1(top_dir: &DirectoryEntry) -> usize {
+        top_dir.sum_size_below(100_000)
+    }
+
+    fn part2(top_dir: &DirectoryEntry) -> usize {
+        let total_size = top_dir.get_size();
+        let free_space = FILESYSTEM_SIZE.saturating_sub(total_size);
+        let to_free = UPDATE_SIZE.saturating_sub(free_space);
+
+        //Nothing has to be deleted if there is enough space already
+        if to_free == 0 {
+            return 0;
+        }
+
+        debug!("Need {} more bytes!", to_free);
+
+        top_dir.find_smallest_above(to_free)
+    }
+}
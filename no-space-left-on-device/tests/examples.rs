@@ -0,0 +1,16 @@
+use no_space_left_on_device::NoSpaceLeftOnDevice;
+
+aoc_common::examples! {
+    NoSpaceLeftOnDevice;
+    example: "test.txt" => (Some(95437), Some(24933642)),
+    task: "task.txt" => (Some(1334506), Some(7421137)),
+}
+
+#[test]
+fn enough_free_space() {
+    aoc_common::testing::check::<NoSpaceLeftOnDevice>(
+        "$ cd /\n$ ls\n100 a\n",
+        Some(100),
+        Some(0),
+    );
+}
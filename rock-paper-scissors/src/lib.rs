@@ -0,0 +1,154 @@
+mod optimise;
+mod rules;
+
+use aoc_common::{ParseError, Solution};
+use log::{debug, trace, warn};
+
+pub use optimise::Plan;
+pub use rules::{Hand, NeededOutcome, Preset, Rules, RulesError};
+
+/// One line of the strategy guide. The second column is a hand in part one and an outcome in
+/// part two, so it is resolved for both. Either may be missing if the rules use different symbols
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    /// Index of the opponent's hand
+    pub opponent: usize,
+    pub hand: Option<usize>,
+    pub outcome: Option<NeededOutcome>,
+}
+
+/// The strategy guide, with the rules it is played by
+#[derive(Debug, Clone)]
+pub struct Guide {
+    pub rules: Rules,
+    pub rounds: Vec<Round>,
+}
+
+fn symbols(symbols: &[char]) -> String {
+    symbols
+        .iter()
+        .map(char::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn parse_round(rules: &Rules, index: usize, line: &str) -> Result<Round, ParseError> {
+    let Some((a, b)) = line.split_once(' ') else {
+        return Err(ParseError::at_line(index, line, "Expected two columns"));
+    };
+
+    let column = |token: &str, valid: &[char]| {
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if valid.contains(&c) => Ok(c),
+            _ => Err(ParseError::at_token(
+                index,
+                line,
+                token,
+                format!("Expected one of {}", symbols(valid)),
+            )),
+        }
+    };
+
+    let a = column(a, &rules.opponent_symbols())?;
+    let b = column(b, &rules.second_column_symbols())?;
+
+    Ok(Round {
+        opponent: rules.opponent_hand(a).unwrap().index(),
+        hand: rules.player_hand(b).map(|hand| hand.index()),
+        outcome: rules.outcome(b),
+    })
+}
+
+/// Parses one round per line, with symbols from `rules`
+pub fn parse_guide(input: &str, rules: Rules) -> Result<Guide, ParseError> {
+    let rounds = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_round(&rules, index, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    debug!(
+        "Parsed {} rounds with {} hands",
+        rounds.len(),
+        rules.hands().count()
+    );
+
+    Ok(Guide { rules, rounds })
+}
+
+impl Guide {
+    pub fn opponent(&self, round: &Round) -> Hand<'_> {
+        self.rules.hand(round.opponent)
+    }
+
+    /// Part one: the second column is my hand. Rounds where it is not a hand are left out
+    /// with a warning. Scores are 64-bit, since rules files can have large hand scores
+    pub fn hand_scores(&self) -> u64 {
+        self.rounds
+            .iter()
+            .enumerate()
+            .filter_map(|(i, round)| {
+                let Some(hand) = round.hand else {
+                    warn!(
+                        "Skipping line {} in part one, the second column is not a hand",
+                        i + 1
+                    );
+                    return None;
+                };
+                let other = self.opponent(round);
+
+                Some(self.rules.hand(hand).score(&other))
+            })
+            .sum::<u64>()
+    }
+
+    /// Part two: the second column is the outcome I need. Rounds where it is not an outcome
+    /// are left out with a warning
+    pub fn outcome_scores(&self) -> u64 {
+        self.rounds
+            .iter()
+            .enumerate()
+            .filter_map(|(i, round)| {
+                let Some(outcome) = round.outcome else {
+                    warn!(
+                        "Skipping line {} in part two, the second column is not an outcome",
+                        i + 1
+                    );
+                    return None;
+                };
+                let opponents_hand = self.opponent(round);
+
+                let score = opponents_hand.score_with_outcome(outcome);
+
+                trace!(
+                    "Opponent played {opponents_hand:?} and the outcome is {outcome:?}. Score: {score}"
+                );
+
+                Some(score)
+            })
+            .sum::<u64>()
+    }
+}
+
+/// Day 2, with the rules from the puzzle
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    const DAY: u8 = 2;
+
+    type Parsed = Guide;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Guide, ParseError> {
+        parse_guide(input, Rules::default())
+    }
+
+    fn part1(guide: &Guide) -> u64 {
+        guide.hand_scores()
+    }
+
+    fn part2(guide: &Guide) -> u64 {
+        guide.outcome_scores()
+    }
+}
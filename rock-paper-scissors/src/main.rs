@@ -1,141 +1,175 @@
 use std::{
     error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+    time::Instant,
 };
 
-#[derive(Debug, Clone, Copy)]
-enum NeededOutcome {
-    Win = 6,
-    Loss = 0,
-    Draw = 3,
-}
+use aoc_common::{
+    cli::{self, DayArgs},
+    input::InputSource,
+    output::{self, Format},
+    Answer, Part, Solution,
+};
+use clap::Parser;
+use rock_paper_scissors::{parse_guide, Guide, Plan, Preset, RockPaperScissors, Rules};
+use serde_json::{json, Value};
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Hand {
-    Rock = 1,
-    Paper = 2,
-    Scissors = 3,
-}
+const DEFAULT_INPUT: &str = "./task.txt";
 
-impl Hand {
-    fn score(&self, other: &Hand) -> u32 {
-        (*self as u32) + self._win_score(other)
-    }
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: DayArgs,
 
-    fn from_char(c: char, is_part_one: bool) -> Option<Self> {
-        use Hand::*;
+    /// Play by one of the built-in rules
+    #[arg(long, value_enum)]
+    preset: Option<Preset>,
 
-        match (c.to_ascii_uppercase(), is_part_one) {
-            ('A', _) | ('X', true) => Some(Rock),
-            ('B', _) | ('Y', true) => Some(Paper),
-            ('C', _) | ('Z', true) => Some(Scissors),
-            _ => None,
-        }
-    }
+    /// Play by the hands, scores and symbols of a TOML rules file, see `rules/` for examples
+    #[arg(long, value_name = "FILE", conflicts_with = "preset")]
+    rules: Option<PathBuf>,
 
-    fn score_with_outcome(&self, outcome: NeededOutcome) -> u32 {
-        use Hand::*;
-        use NeededOutcome::*;
+    /// Compare the guide with the best, the worst and an inconspicuous way to play it
+    #[arg(long)]
+    optimise: bool,
 
-        let other_hand = match (self, &outcome) {
-            (&a, Draw) => a,
+    /// How many rounds the inconspicuous plan wins. Defaults to a third, like chance would
+    #[arg(long, value_name = "N", requires = "optimise")]
+    wins: Option<usize>,
 
-            (Rock, Loss) => Scissors,
-            (Rock, Win) => Paper,
+    /// Also list the hands every plan plays in each round
+    #[arg(long, requires = "optimise")]
+    rounds: bool,
+}
 
-            (Paper, Loss) => Rock,
-            (Paper, Win) => Scissors,
+fn load_rules(cli: &Cli) -> Result<Rules, Box<dyn Error>> {
+    let Some(path) = &cli.rules else {
+        return Ok(Rules::preset(cli.preset.unwrap_or_default()));
+    };
 
-            (Scissors, Loss) => Paper,
-            (Scissors, Win) => Rock,
-        };
+    let source = InputSource::from_path(path.clone());
+    Ok(Rules::from_toml(&source.read()?).map_err(|e| format!("{source}: {e}"))?)
+}
 
-        println!("The hand needed for outcome {outcome:?} is {other_hand:?}");
+fn solve(source: &InputSource, cli: &Cli) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let guide = load_guide(source, cli)?;
 
-        other_hand as u32 + outcome as u32
-    }
+    let start = Instant::now();
+    let hands = cli::answer(Part::One, guide.hand_scores(), start);
+    let start = Instant::now();
+    let outcomes = cli::answer(Part::Two, guide.outcome_scores(), start);
 
-    //Rock defeats Scissors, Scissors defeats Paper, and Paper defeats Rock
-    fn _win_score(&self, other: &Hand) -> u32 {
-        use Hand::*;
+    Ok(vec![hands, outcomes])
+}
 
-        match (self, other) {
-            (Rock, Scissors) | (Scissors, Paper) | (Paper, Rock) => 6,
-            (a, b) if a == b => 3,
-            (_, _) => 0,
-        }
-    }
+fn load_guide(source: &InputSource, cli: &Cli) -> Result<Guide, Box<dyn Error>> {
+    Ok(parse_guide(&source.read()?, load_rules(cli)?).map_err(|e| e.with_file(source))?)
 }
 
-impl NeededOutcome {
-    fn from_char(c: char) -> Option<Self> {
-        use NeededOutcome::*;
+//How far from the worst to the best score a plan gets
+fn rating(plan: &Plan, worst: u64, best: u64) -> f64 {
+    if best == worst {
+        return 100.0;
+    }
+
+    (plan.score - worst) as f64 * 100.0 / (best - worst) as f64
+}
 
-        match c {
-            'X' => Some(Loss),
-            'Y' => Some(Draw),
-            'Z' => Some(Win),
-            _ => None,
+fn print_optimisation(format: Format, guide: &Guide, cli: &Cli) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    let best = guide.best_plan();
+    let worst = guide.worst_plan();
+    let wins = cli.wins.unwrap_or(guide.rounds.len() / 3);
+    let inconspicuous = guide.inconspicuous_plan(wins);
+    let (hands, outcomes) = (guide.guide_plan(), guide.outcome_plan());
+    let plans = [
+        ("best", Some(&best)),
+        ("worst", Some(&worst)),
+        ("inconspicuous", Some(&inconspicuous)),
+        ("guide_hands", hands.as_ref()),
+        ("guide_outcomes", outcomes.as_ref()),
+    ];
+
+    match format {
+        Format::Text => {
+            if cli.rounds {
+                for (i, round) in guide.rounds.iter().enumerate() {
+                    writeln!(
+                        out,
+                        "round {}: against {} play {} for the most points, {} for the least, {} to stay inconspicuous",
+                        i + 1,
+                        guide.opponent(round),
+                        best.hands[i],
+                        worst.hands[i],
+                        inconspicuous.hands[i],
+                    )?;
+                }
+            }
+
+            for (name, plan) in plans {
+                let name = name.replace('_', " ");
+                match plan {
+                    Some(plan) => writeln!(
+                        out,
+                        "{name}: {} points, {} of {} rounds won ({:.1}% of the way from the worst to the best score)",
+                        plan.score,
+                        plan.wins,
+                        guide.rounds.len(),
+                        rating(plan, worst.score, best.score),
+                    )?,
+                    None => writeln!(out, "{name}: the second column does not fit these rules")?,
+                }
+            }
+        }
+        Format::Json => {
+            let plans = plans
+                .into_iter()
+                .map(|(name, plan)| {
+                    let plan = plan.map_or(Value::Null, |plan| {
+                        let mut value = json!({
+                            "score": plan.score,
+                            "wins": plan.wins,
+                            "rating": rating(plan, worst.score, best.score),
+                        });
+                        if cli.rounds {
+                            value["hands"] = plan.hands.iter().map(|hand| hand.name()).collect();
+                        }
+                        value
+                    });
+                    (name.to_owned(), plan)
+                })
+                .collect::<serde_json::Map<_, _>>();
+
+            writeln!(out, "{}", Value::Object(plans))?
         }
     }
+    out.flush()
 }
 
-fn part_one(input: &Vec<String>) {
-    let hands = input
-        .iter()
-        .map(|line| {
-            line.splitn(2, ' ')
-                .flat_map(|chunk| chunk.chars().nth(0))
-                .flat_map(|c| Hand::from_char(c, true))
-                .collect::<Vec<_>>()
-        })
-        .map(|x| {
-            let [a, b] = x[..2] else { unreachable!() };
-            (a, b)
-        })
-        .collect::<Vec<_>>();
-
-    let total_score = hands.iter().map(|(other, me)| me.score(other)).sum::<u32>();
+fn optimise(source: &InputSource, cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let guide = load_guide(source, cli)?;
 
-    println!("Total score: {total_score}");
+    Ok(print_optimisation(cli.args.format, &guide, cli)?)
 }
 
-fn part_two(input: &Vec<String>) {
-    let score = input
-        .iter()
-        .map(|line| {
-            line.splitn(2, ' ')
-                .flat_map(|chunk| chunk.chars().nth(0))
-                .collect::<Vec<_>>()
-        })
-        .map(|x| {
-            let [a, b] = x[..2] else { unreachable!() };
-
-            let opponents_hand = Hand::from_char(a, false).unwrap();
-            let outcome = NeededOutcome::from_char(b).unwrap();
-
-            let score = opponents_hand.score_with_outcome(outcome);
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-            println!(
-                "Opponent played {opponents_hand:?} and the outcome is {outcome:?}. Score: {score}"
-            );
+    if cli.preset.is_none() && cli.rules.is_none() && !cli.optimise {
+        return cli::run_collected::<RockPaperScissors>(&cli.args, DEFAULT_INPUT);
+    }
 
-            score
+    let source = cli.args.init(DEFAULT_INPUT);
+    let result = if cli.optimise {
+        optimise(&source, &cli)
+    } else {
+        solve(&source, &cli).map(|answers| {
+            output::print_answers(cli.args.format, RockPaperScissors::DAY, &source, &answers)
         })
-        .sum::<u32>();
-
-    println!("Score: {score}");
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = BufReader::new(File::open("./task.txt")?);
-
-    let lines = file.lines().flatten().collect();
-
-    part_one(&lines);
-
-    part_two(&lines);
+    };
 
-    Ok(())
+    cli::exit_code(result)
 }
@@ -0,0 +1,119 @@
+use std::cmp::Reverse;
+
+use crate::{Guide, Hand, NeededOutcome};
+
+/// A hand for every round of a guide, and what playing them scores
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan<'r> {
+    pub hands: Vec<Hand<'r>>,
+    pub score: u64,
+    pub wins: usize,
+}
+
+impl<'r> Plan<'r> {
+    fn new(guide: &'r Guide, hands: Vec<Hand<'r>>) -> Self {
+        let opponents = guide.rounds.iter().map(|round| guide.opponent(round));
+        let (score, wins) =
+            hands
+                .iter()
+                .zip(opponents)
+                .fold((0, 0), |(score, wins), (hand, other)| {
+                    let won = hand.outcome_against(&other) == NeededOutcome::Win;
+                    (score + hand.score(&other), wins + won as usize)
+                });
+
+        Self { hands, score, wins }
+    }
+}
+
+impl Guide {
+    fn plan_by<'r>(&'r self, choose: impl Fn(Hand<'r>) -> Hand<'r>) -> Plan<'r> {
+        let hands = self
+            .rounds
+            .iter()
+            .map(|round| choose(self.opponent(round)))
+            .collect();
+
+        Plan::new(self, hands)
+    }
+
+    /// The hands scoring the most in every round
+    pub fn best_plan(&self) -> Plan<'_> {
+        self.plan_by(|other| {
+            self.rules
+                .hands()
+                .max_by_key(|hand| (hand.score(&other), Reverse(hand.index())))
+                .unwrap()
+        })
+    }
+
+    /// The hands scoring the least in every round
+    pub fn worst_plan(&self) -> Plan<'_> {
+        self.plan_by(|other| {
+            self.rules
+                .hands()
+                .min_by_key(|hand| (hand.score(&other), hand.index()))
+                .unwrap()
+        })
+    }
+
+    /// The best plan that wins exactly `wins` rounds (or all of them if there are fewer), so
+    /// that winning does not look suspicious
+    pub fn inconspicuous_plan(&self, wins: usize) -> Plan<'_> {
+        use NeededOutcome::*;
+
+        //What each round scores when not winning, and how much a win would add to that
+        let mut rounds = self
+            .rounds
+            .iter()
+            .enumerate()
+            .map(|(i, round)| {
+                let other = self.opponent(round);
+                let other_outcome = [Draw, Loss]
+                    .into_iter()
+                    .max_by_key(|&outcome| other.score_with_outcome(outcome))
+                    .unwrap();
+
+                let gain = other.score_with_outcome(Win) as i64
+                    - other.score_with_outcome(other_outcome) as i64;
+                (i, other_outcome, gain)
+            })
+            .collect::<Vec<_>>();
+        rounds.sort_by_key(|&(i, _, gain)| (Reverse(gain), i));
+
+        let mut outcomes = vec![Draw; self.rounds.len()];
+        for (rank, &(i, other_outcome, _)) in rounds.iter().enumerate() {
+            outcomes[i] = if rank < wins { Win } else { other_outcome };
+        }
+
+        let hands = self
+            .rounds
+            .iter()
+            .zip(outcomes)
+            .map(|(round, outcome)| self.opponent(round).for_outcome(outcome))
+            .collect();
+        Plan::new(self, hands)
+    }
+
+    /// The hands part one plays. `None` if the second column is not always a hand
+    pub fn guide_plan(&self) -> Option<Plan<'_>> {
+        let hands = self
+            .rounds
+            .iter()
+            .map(|round| Some(self.rules.hand(round.hand?)))
+            .collect::<Option<_>>()?;
+
+        Some(Plan::new(self, hands))
+    }
+
+    /// The hands part two plays. `None` if the second column is not always an outcome
+    pub fn outcome_plan(&self) -> Option<Plan<'_>> {
+        let hands = self
+            .rounds
+            .iter()
+            .map(|round| Some(self.opponent(round).for_outcome(round.outcome?)))
+            .collect::<Option<_>>()?;
+
+        Some(Plan::new(self, hands))
+    }
+}
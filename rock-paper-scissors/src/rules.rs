@@ -0,0 +1,332 @@
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+/// The outcome of a round, from my point of view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NeededOutcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl NeededOutcome {
+    pub const ALL: [NeededOutcome; 3] =
+        [NeededOutcome::Loss, NeededOutcome::Draw, NeededOutcome::Win];
+}
+
+/// Built-in rules
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Preset {
+    /// Rock, Paper, Scissors
+    #[default]
+    Classic,
+    /// Rock, Paper, Scissors, Lizard, Spock
+    Rpsls,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename = "outcome")]
+struct RawOutcome {
+    score: u32,
+    symbol: char,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename = "outcomes")]
+struct RawOutcomes {
+    loss: RawOutcome,
+    draw: RawOutcome,
+    win: RawOutcome,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename = "hand")]
+struct RawHand {
+    name: String,
+    score: u32,
+    opponent: char,
+    player: char,
+    #[serde(default)]
+    beats: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename = "rules")]
+struct RawRules {
+    outcomes: RawOutcomes,
+    hands: Vec<RawHand>,
+}
+
+#[derive(Debug)]
+pub enum RulesError {
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesError::Toml(e) => write!(f, "Invalid rules file: {e}"),
+            RulesError::Invalid(message) => write!(f, "Invalid rules: {message}"),
+        }
+    }
+}
+
+impl Error for RulesError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HandRules {
+    name: String,
+    score: u32,
+    opponent: char,
+    player: char,
+    beats: Vec<usize>,
+}
+
+/// Which hands there are, which ones beat which, and how rounds are scored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    hands: Vec<HandRules>,
+    /// Score and symbol of each outcome, in the order of [`NeededOutcome::ALL`]
+    outcomes: [(u32, char); 3],
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::preset(Preset::default())
+    }
+}
+
+fn unique<T: Eq + std::hash::Hash + Display>(
+    values: impl IntoIterator<Item = T>,
+    what: &str,
+) -> Result<(), RulesError> {
+    let mut seen = HashSet::new();
+
+    for value in values {
+        if seen.contains(&value) {
+            return Err(RulesError::Invalid(format!(
+                "{what} '{value}' is used twice"
+            )));
+        }
+        seen.insert(value);
+    }
+    Ok(())
+}
+
+impl Rules {
+    pub fn preset(preset: Preset) -> Self {
+        let toml = match preset {
+            Preset::Classic => include_str!("../rules/classic.toml"),
+            Preset::Rpsls => include_str!("../rules/rpsls.toml"),
+        };
+
+        Self::from_toml(toml).expect("presets are valid")
+    }
+
+    /// Reads rules like the ones in `rules/`. Every hand has to beat at least one hand and be
+    /// beaten by at least one, so that every outcome can be reached
+    pub fn from_toml(toml: &str) -> Result<Self, RulesError> {
+        let RawRules { outcomes, hands } = toml::from_str(toml).map_err(RulesError::Toml)?;
+
+        if hands.is_empty() {
+            return Err(RulesError::Invalid("There are no hands".to_owned()));
+        }
+        unique(hands.iter().map(|hand| &hand.name), "Hand")?;
+        unique(hands.iter().map(|hand| hand.opponent), "Opponent symbol")?;
+        unique(hands.iter().map(|hand| hand.player), "Player symbol")?;
+
+        let outcomes = [outcomes.loss, outcomes.draw, outcomes.win].map(|o| (o.score, o.symbol));
+        unique(outcomes.iter().map(|&(_, symbol)| symbol), "Outcome symbol")?;
+
+        let index = |name: &str| hands.iter().position(|hand| hand.name == name);
+        let hands = hands
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| {
+                let beats = hand
+                    .beats
+                    .iter()
+                    .map(|name| match index(name) {
+                        Some(j) if j == i => Err(RulesError::Invalid(format!(
+                            "{} cannot beat itself",
+                            hand.name
+                        ))),
+                        Some(j) => Ok(j),
+                        None => Err(RulesError::Invalid(format!(
+                            "{} beats '{name}', which is not a hand",
+                            hand.name
+                        ))),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(HandRules {
+                    name: hand.name.clone(),
+                    score: hand.score,
+                    opponent: hand.opponent,
+                    player: hand.player,
+                    beats,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let rules = Self { hands, outcomes };
+        for a in rules.hands() {
+            for b in rules.hands() {
+                if a.beats(&b) && b.beats(&a) {
+                    return Err(RulesError::Invalid(format!("{a} and {b} beat each other")));
+                }
+            }
+
+            if !rules.hands().any(|b| a.beats(&b)) {
+                return Err(RulesError::Invalid(format!("{a} does not beat any hand")));
+            }
+            if !rules.hands().any(|b| b.beats(&a)) {
+                return Err(RulesError::Invalid(format!("No hand beats {a}")));
+            }
+        }
+
+        Ok(rules)
+    }
+
+    pub fn hands(&self) -> impl Iterator<Item = Hand<'_>> {
+        (0..self.hands.len()).map(|index| self.hand(index))
+    }
+
+    /// Panics if there is no hand at `index`
+    pub fn hand(&self, index: usize) -> Hand<'_> {
+        assert!(index < self.hands.len(), "No hand at index {index}");
+
+        Hand { rules: self, index }
+    }
+
+    /// The hand the opponent's column names
+    pub fn opponent_hand(&self, symbol: char) -> Option<Hand<'_>> {
+        self.hands().find(|hand| hand.rules().opponent == symbol)
+    }
+
+    /// The hand the second column names in part one
+    pub fn player_hand(&self, symbol: char) -> Option<Hand<'_>> {
+        self.hands().find(|hand| hand.rules().player == symbol)
+    }
+
+    /// The outcome the second column names in part two
+    pub fn outcome(&self, symbol: char) -> Option<NeededOutcome> {
+        NeededOutcome::ALL
+            .into_iter()
+            .find(|&outcome| self.outcome_symbol(outcome) == symbol)
+    }
+
+    pub fn outcome_score(&self, outcome: NeededOutcome) -> u32 {
+        self.outcomes[outcome as usize].0
+    }
+
+    pub fn outcome_symbol(&self, outcome: NeededOutcome) -> char {
+        self.outcomes[outcome as usize].1
+    }
+
+    pub fn opponent_symbols(&self) -> Vec<char> {
+        self.hands.iter().map(|hand| hand.opponent).collect()
+    }
+
+    /// Every symbol the second column may have, in either part
+    pub fn second_column_symbols(&self) -> Vec<char> {
+        let mut symbols = self
+            .hands
+            .iter()
+            .map(|hand| hand.player)
+            .chain(self.outcomes.iter().map(|&(_, symbol)| symbol))
+            .collect::<Vec<_>>();
+        symbols.sort_unstable();
+        symbols.dedup();
+        symbols
+    }
+}
+
+/// One of the hands of a set of [`Rules`]
+#[derive(Clone, Copy)]
+pub struct Hand<'r> {
+    rules: &'r Rules,
+    index: usize,
+}
+
+impl<'r> Hand<'r> {
+    fn rules(&self) -> &'r HandRules {
+        &self.rules.hands[self.index]
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn name(&self) -> &'r str {
+        &self.rules().name
+    }
+
+    /// The score for playing this hand, whatever the outcome
+    pub fn value(&self) -> u32 {
+        self.rules().score
+    }
+
+    pub fn beats(&self, other: &Hand) -> bool {
+        self.rules().beats.contains(&other.index)
+    }
+
+    pub fn outcome_against(&self, other: &Hand) -> NeededOutcome {
+        use NeededOutcome::*;
+
+        if self.beats(other) {
+            Win
+        } else if other.beats(self) {
+            Loss
+        } else {
+            Draw
+        }
+    }
+
+    /// My score for playing this hand against `other`
+    pub fn score(&self, other: &Hand) -> u64 {
+        u64::from(self.value()) + u64::from(self.rules.outcome_score(self.outcome_against(other)))
+    }
+
+    /// The hand to play against this one to get `outcome`. If several hands do, this is the one
+    /// scoring the most. Hands other than the opponent's may draw too if neither beats the other
+    pub fn for_outcome(&self, outcome: NeededOutcome) -> Hand<'r> {
+        self.rules
+            .hands()
+            .filter(|hand| hand.outcome_against(self) == outcome)
+            .max_by_key(|hand| (hand.value(), std::cmp::Reverse(hand.index)))
+            .expect("the rules make every outcome reachable")
+    }
+
+    /// My score when the opponent plays this hand and I play for `outcome`
+    pub fn score_with_outcome(&self, outcome: NeededOutcome) -> u64 {
+        self.for_outcome(outcome).score(self)
+    }
+}
+
+impl PartialEq for Hand<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.rules, other.rules) && self.index == other.index
+    }
+}
+
+impl Eq for Hand<'_> {}
+
+impl Debug for Hand<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Display for Hand<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,7 @@
+use rock_paper_scissors::RockPaperScissors;
+
+aoc_common::examples! {
+    RockPaperScissors;
+    example: "test.txt" => (Some(15), Some(12)),
+    task: "task.txt" => (Some(12276), Some(9975)),
+}
@@ -0,0 +1,108 @@
+use aoc_common::Solution;
+use rock_paper_scissors::{parse_guide, Guide, NeededOutcome, Preset, RockPaperScissors, Rules};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+//Every combination of hands, with its score and number of wins
+fn all_plans(guide: &Guide) -> Vec<(u64, usize)> {
+    let hands = guide.rules.hands().count();
+
+    (0..hands.pow(guide.rounds.len() as u32))
+        .map(|mut combination| {
+            guide.rounds.iter().fold((0, 0), |(score, wins), round| {
+                let me = guide.rules.hand(combination % hands);
+                let other = guide.opponent(round);
+                combination /= hands;
+
+                let won = me.outcome_against(&other) == NeededOutcome::Win;
+                (score + me.score(&other), wins + won as usize)
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    let guide = RockPaperScissors::parse(EXAMPLE).unwrap();
+
+    let best = guide.best_plan();
+    assert_eq!((best.score, best.wins), (24, 3));
+    assert_eq!(
+        best.hands
+            .iter()
+            .map(|hand| hand.name())
+            .collect::<Vec<_>>(),
+        ["Paper", "Scissors", "Rock"]
+    );
+    assert_eq!(guide.worst_plan().score, 6);
+
+    let inconspicuous = guide.inconspicuous_plan(1);
+    assert_eq!((inconspicuous.score, inconspicuous.wins), (19, 1));
+
+    assert_eq!(guide.guide_plan().unwrap().score, 15);
+    assert_eq!(guide.outcome_plan().unwrap().score, 12);
+}
+
+#[test]
+fn matches_brute_force() {
+    for preset in [Preset::Classic, Preset::Rpsls] {
+        let rules = Rules::preset(preset);
+        let symbols = rules.opponent_symbols();
+
+        for guide in 0..symbols.len().pow(4) {
+            let input = (0..4)
+                .map(|i| {
+                    let symbol = symbols[guide / symbols.len().pow(i) % symbols.len()];
+                    format!("{symbol} X\n")
+                })
+                .collect::<String>();
+            let guide = parse_guide(&input, rules.clone()).unwrap();
+            let plans = all_plans(&guide);
+
+            assert_eq!(
+                guide.best_plan().score,
+                plans.iter().map(|p| p.0).max().unwrap()
+            );
+            assert_eq!(
+                guide.worst_plan().score,
+                plans.iter().map(|p| p.0).min().unwrap()
+            );
+
+            for wins in 0..=4 {
+                let plan = guide.inconspicuous_plan(wins);
+                let best = plans.iter().filter(|p| p.1 == wins).map(|p| p.0).max();
+
+                assert_eq!(plan.wins, wins, "{input}");
+                assert_eq!(Some(plan.score), best, "{input}");
+            }
+        }
+    }
+}
+
+#[test]
+fn task() {
+    let guide = RockPaperScissors::parse(include_str!("../task.txt")).unwrap();
+    let (best, worst) = (guide.best_plan(), guide.worst_plan());
+
+    for plan in [
+        guide.guide_plan().unwrap(),
+        guide.outcome_plan().unwrap(),
+        guide.inconspicuous_plan(guide.rounds.len() / 3),
+    ] {
+        assert!((worst.score..=best.score).contains(&plan.score));
+    }
+    assert_eq!(guide.guide_plan().unwrap().score, 12276);
+    assert_eq!(guide.outcome_plan().unwrap().score, 9975);
+
+    //Asking for more wins than rounds wins all of them
+    let all = guide.inconspicuous_plan(usize::MAX);
+    assert_eq!((all.score, all.wins), (best.score, guide.rounds.len()));
+}
+
+#[test]
+fn guides_that_do_not_fit_the_rules() {
+    let guide = parse_guide("A V\nB X\n", Rules::preset(Preset::Rpsls)).unwrap();
+
+    assert!(guide.guide_plan().is_some());
+    assert!(guide.outcome_plan().is_none());
+}
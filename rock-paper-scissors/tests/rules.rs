@@ -0,0 +1,89 @@
+use aoc_common::Solution;
+use rock_paper_scissors::{parse_guide, NeededOutcome, Preset, RockPaperScissors, Rules};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn classic_rules() {
+    let rules = Rules::preset(Preset::Classic);
+    let [rock, paper, scissors] = ["Rock", "Paper", "Scissors"]
+        .map(|name| rules.hands().find(|hand| hand.name() == name).unwrap());
+
+    assert!(rock.beats(&scissors) && scissors.beats(&paper) && paper.beats(&rock));
+    assert_eq!(paper.score(&rock), 8);
+    assert_eq!(rock.score(&paper), 1);
+    assert_eq!(scissors.score(&scissors), 6);
+
+    assert_eq!(rock.for_outcome(NeededOutcome::Win), paper);
+    assert_eq!(rock.for_outcome(NeededOutcome::Loss), scissors);
+    assert_eq!(rock.score_with_outcome(NeededOutcome::Draw), 4);
+
+    assert_eq!(rules.opponent_hand('B'), Some(paper));
+    assert_eq!(rules.player_hand('Z'), Some(scissors));
+    assert_eq!(rules.outcome('Y'), Some(NeededOutcome::Draw));
+}
+
+#[test]
+fn rpsls() {
+    let rules = Rules::preset(Preset::Rpsls);
+    assert_eq!(rules.hands().count(), 5);
+    for hand in rules.hands() {
+        assert_eq!(rules.hands().filter(|other| hand.beats(other)).count(), 2);
+    }
+
+    //Both Paper and Spock beat Rock, Spock scores more
+    let rock = rules.opponent_hand('A').unwrap();
+    assert_eq!(rock.for_outcome(NeededOutcome::Win).name(), "Spock");
+
+    //The classic symbols still mean the same hands
+    let guide = parse_guide(EXAMPLE, rules.clone()).unwrap();
+    assert_eq!(guide.hand_scores(), 15);
+    //Losing to Paper and beating Scissors both score more with Spock than with Rock
+    assert_eq!(guide.outcome_scores(), 4 + 5 + 11);
+
+    //Lizard and Spock are hands in part one but not outcomes in part two
+    let guide = parse_guide("A V\nE W\nD Z\n", rules).unwrap();
+    assert_eq!(guide.hand_scores(), 4 + 8 + 9);
+    assert_eq!(guide.outcome_scores(), 9);
+}
+
+#[test]
+fn rules_files() {
+    let classic = include_str!("../rules/classic.toml");
+    assert_eq!(Rules::from_toml(classic).unwrap(), Rules::default());
+
+    let invalid = |from: &str, to: &str| {
+        Rules::from_toml(&classic.replacen(from, to, 1))
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(invalid("\"Scissors\"]", "\"Lizard\"]").contains("'Lizard'"));
+    assert!(invalid("\"Scissors\"]", "\"Rock\"]").contains("itself"));
+    assert!(invalid("opponent = \"B\"", "opponent = \"A\"").contains("'A' is used twice"));
+    assert!(invalid("symbol = \"Z\"", "symbol = \"X\"").contains("'X' is used twice"));
+    assert!(invalid("score = 1", "score = \"one\"").contains("Invalid rules file"));
+    assert!(invalid("beats = [\"Paper\"]", "beats = []").contains("No hand beats Paper"));
+}
+
+#[test]
+fn large_scores() {
+    let classic = include_str!("../rules/classic.toml");
+    let rules = Rules::from_toml(&classic.replacen("score = 1", "score = 4000000000", 1)).unwrap();
+    let guide = parse_guide("A X\nA X\n", rules).unwrap();
+
+    assert_eq!(guide.hand_scores(), 2 * 4_000_000_003);
+    assert_eq!(guide.best_plan().score, 2 * 4_000_000_003);
+}
+
+#[test]
+fn symbols_come_from_the_rules() {
+    assert!(RockPaperScissors::parse("D X\n").is_err());
+
+    let guide = parse_guide("D X\n", Rules::preset(Preset::Rpsls)).unwrap();
+    assert_eq!(guide.hand_scores(), 1 + 6);
+
+    let error = parse_guide("A Q\n", Rules::preset(Preset::Rpsls)).unwrap_err();
+    assert_eq!(error.column(), 3);
+    assert_eq!(error.message(), "Expected one of V, W, X, Y, Z");
+}
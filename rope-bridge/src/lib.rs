@@ -0,0 +1,211 @@
+use std::collections::HashSet;
+
+use aoc_common::{stream::StreamingSolution, ParseError, Solution};
+use grid::Point;
+use log::{debug, log_enabled, Level};
+
+#[derive(Debug)]
+struct State<const N: usize> {
+    knots: [Point; N],
+    visited: HashSet<Point>,
+    top_right_bound: Point,
+    bottom_left_bound: Point,
+}
+
+impl<const N: usize> Default for State<N> {
+    fn default() -> Self {
+        State {
+            knots: [Point::ORIGIN; N],
+            visited: HashSet::from([Point::ORIGIN]),
+            top_right_bound: Point::ORIGIN,
+            bottom_left_bound: Point::ORIGIN,
+        }
+    }
+}
+
+impl<const N: usize> State<N> {
+    fn head(&self) -> &Point {
+        &self.knots[0]
+    }
+
+    fn tail(&self) -> &Point {
+        &self.knots[N - 1]
+    }
+
+    fn update_head(&mut self, step: Point) {
+        self.knots[0] += step;
+    }
+}
+
+#[derive(Debug)]
+pub struct Move(i32, i32);
+
+fn parse_move(index: usize, line: &str) -> Result<Move, ParseError> {
+    let Some((direction, amount)) = line.split_once(' ') else {
+        return Err(ParseError::at_line(
+            index,
+            line,
+            "Expected '<direction> <amount>'",
+        ));
+    };
+    let amount = amount
+        .parse()
+        .map_err(|e| ParseError::at_token(index, line, amount, format!("Invalid amount: {e}")))?;
+
+    match direction {
+        "R" => Ok(Move(amount, 0)),
+        "L" => Ok(Move(-amount, 0)),
+        "U" => Ok(Move(0, -amount)),
+        "D" => Ok(Move(0, amount)),
+        _ => Err(ParseError::at_token(
+            index,
+            line,
+            direction,
+            "Unknown direction, expected one of R, L, U, D",
+        )),
+    }
+}
+
+fn is_touching(head: &Point, tail: &Point) -> bool {
+    head.chebyshev_distance(tail) <= 1
+}
+
+fn update_knot(head: &Point, mut tail: Point) -> Point {
+    if is_touching(head, &tail) {
+        return tail;
+    }
+
+    tail += (*head - tail).signum();
+    tail
+}
+
+fn update_knots<const N: usize>(state: &mut State<N>) {
+    for i in 1..N {
+        state.knots[i] = update_knot(&state.knots[i - 1], state.knots[i]);
+    }
+}
+
+fn update<const N: usize>(old: State<N>, dx: i32, dy: i32) -> State<N> {
+    let mut new = old;
+
+    //Where to go on x/y each step (one of these is always zero)
+    let step = Point::new(dx, dy).signum();
+
+    //How many steps to move
+    let num_steps = dx.abs().max(dy.abs());
+
+    for _ in 0..num_steps {
+        new.update_head(step);
+
+        update_knots(&mut new);
+
+        new.visited.insert(*new.tail());
+    }
+
+    //Update the bounds
+    new.top_right_bound = new.top_right_bound.max(*new.head()).max(*new.tail());
+    new.bottom_left_bound = new.bottom_left_bound.min(*new.head()).min(*new.tail());
+
+    new
+}
+
+fn knot_name(index: usize) -> char {
+    if index == 0 {
+        return 'H';
+    }
+    (b'0' + index as u8) as char
+}
+
+fn show_state<const N: usize>(state: &State<N>) -> String {
+    let Point { x: min_x, y: min_y } = state.bottom_left_bound;
+    let Point { x: max_x, y: max_y } = state.top_right_bound;
+
+    let mut out = String::new();
+
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let pos = Point::new(x, y);
+
+            let knot = state.knots.iter().position(|knot| pos == *knot);
+
+            if let Some(index) = knot {
+                out.push(knot_name(index));
+            } else if state.visited.contains(&pos) {
+                out.push('#');
+            } else {
+                out.push('.');
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn log_final_state<const N: usize>(state: &State<N>) {
+    if log_enabled!(Level::Debug) {
+        debug!("Final state:\n{}", show_state(state));
+    }
+}
+
+fn simulate<const N: usize>(moves: &[Move]) -> State<N> {
+    let mut state = State::<N>::default();
+
+    for &Move(dx, dy) in moves {
+        state = update(state, dx, dy);
+    }
+
+    log_final_state(&state);
+    state
+}
+
+pub struct RopeBridge;
+
+impl Solution for RopeBridge {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_move(index, line))
+            .collect()
+    }
+
+    fn part1(moves: &Vec<Move>) -> usize {
+        simulate::<2>(moves).visited.len()
+    }
+
+    fn part2(moves: &Vec<Move>) -> usize {
+        simulate::<10>(moves).visited.len()
+    }
+}
+
+/// The short rope of part 1 and the long rope of part 2, simulated side by side
+#[derive(Debug, Default)]
+pub struct Ropes {
+    short: State<2>,
+    long: State<10>,
+}
+
+impl StreamingSolution for RopeBridge {
+    type State = Ropes;
+
+    fn feed(ropes: &mut Ropes, index: usize, line: &str) -> Result<(), ParseError> {
+        let Move(dx, dy) = parse_move(index, line)?;
+
+        ropes.short = update(std::mem::take(&mut ropes.short), dx, dy);
+        ropes.long = update(std::mem::take(&mut ropes.long), dx, dy);
+        Ok(())
+    }
+
+    fn finish(ropes: Ropes) -> Result<(usize, usize), ParseError> {
+        log_final_state(&ropes.short);
+        log_final_state(&ropes.long);
+
+        Ok((ropes.short.visited.len(), ropes.long.visited.len()))
+    }
+}
@@ -0,0 +1,8 @@
+use rope_bridge::RopeBridge;
+
+aoc_common::examples! {
+    RopeBridge;
+    example: "test.txt" => (Some(13), Some(1)),
+    larger_example: "test2.txt" => (Some(88), Some(36)),
+    task: "task.txt" => (Some(6081), Some(2487)),
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use rope_bridge::RopeBridge;
+
+#[test]
+fn rejects_unknown_direction() {
+    let error = RopeBridge::parse("R 4\nX 3\n").unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (2, 1));
+}
+
+#[test]
+fn rejects_missing_amount() {
+    let error = RopeBridge::parse("R\n").unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (1, 1));
+}
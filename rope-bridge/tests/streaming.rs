@@ -0,0 +1,17 @@
+use aoc_common::testing::check_stream;
+use rope_bridge::RopeBridge;
+
+#[test]
+fn example() {
+    check_stream::<RopeBridge>(include_str!("../test.txt"));
+}
+
+#[test]
+fn task() {
+    check_stream::<RopeBridge>(include_str!("../task.txt"));
+}
+
+#[test]
+fn larger_example() {
+    check_stream::<RopeBridge>(include_str!("../test2.txt"));
+}
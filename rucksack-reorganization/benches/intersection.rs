@@ -0,0 +1,141 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rucksack_reorganization::{first_shared_item, Item, PriorityTable, Rucksack};
+
+const ITEM_TYPES: u32 = 52;
+
+//xorshift, so the inputs are the same on every run without pulling in a rand crate
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: u32) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as u32
+    }
+}
+
+//Each rucksack has exactly one item type in both compartments, like the puzzle promises
+fn synthetic(rucksacks: usize, compartment: usize) -> Vec<Rucksack> {
+    let mut rng = Rng(0x2022_0003);
+
+    (0..rucksacks)
+        .map(|_| {
+            let shared = rng.below(ITEM_TYPES);
+            let mut others = (0..ITEM_TYPES).filter(|&t| t != shared).collect::<Vec<_>>();
+            for i in (1..others.len()).rev() {
+                others.swap(i, rng.below(i as u32 + 1) as usize);
+            }
+            let (left, right) = others.split_at(others.len() / 2);
+
+            let mut half = |types: &[u32]| {
+                let mut items = (1..compartment)
+                    .map(|_| Item(types[rng.below(types.len() as u32) as usize]))
+                    .collect::<Vec<_>>();
+                let at = rng.below(compartment as u32) as usize;
+                items.insert(at, Item(shared));
+                items
+            };
+
+            let mut rucksack = half(left);
+            rucksack.extend(half(right));
+            rucksack
+        })
+        .collect()
+}
+
+//Groups of three rucksacks that only have the badge in common
+fn synthetic_groups(groups: usize, rucksack: usize) -> Vec<Rucksack> {
+    let mut rng = Rng(0x2022_0303);
+
+    (0..groups)
+        .flat_map(|_| {
+            let badge = rng.below(ITEM_TYPES);
+            let others = (0..ITEM_TYPES).filter(|&t| t != badge).collect::<Vec<_>>();
+
+            others
+                .chunks(others.len() / 3)
+                .take(3)
+                .map(|types| {
+                    let mut items = (1..rucksack)
+                        .map(|_| Item(types[rng.below(types.len() as u32) as usize]))
+                        .collect::<Vec<_>>();
+                    items.insert(rng.below(rucksack as u32) as usize, Item(badge));
+                    items
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+//What both parts did before masks: look up every item of one side in the other one(s)
+fn scan(parts: &[&[Item]]) -> Option<Item> {
+    let (first, rest) = parts.split_first()?;
+
+    first
+        .iter()
+        .copied()
+        .find(|item| rest.iter().all(|part| part.contains(item)))
+}
+
+fn mask(parts: &[&[Item]]) -> Option<Item> {
+    first_shared_item(parts.iter().copied())
+}
+
+fn compartments(c: &mut Criterion) {
+    let table = PriorityTable::default();
+    let mut group = c.benchmark_group("compartments");
+
+    for compartment in [12, 48, 256] {
+        let rucksacks = synthetic(10_000, compartment);
+        let sum = |find: &dyn Fn(&[&[Item]]) -> Option<Item>| {
+            rucksacks
+                .iter()
+                .map(|rucksack| {
+                    let (first, second) = rucksack.split_at(rucksack.len() / 2);
+                    table.priority(find(&[first, second]).unwrap())
+                })
+                .sum::<u32>()
+        };
+        assert_eq!(sum(&scan), sum(&mask));
+
+        group.bench_with_input(BenchmarkId::new("scan", compartment), &(), |b, _| {
+            b.iter(|| sum(black_box(&scan)))
+        });
+        group.bench_with_input(BenchmarkId::new("mask", compartment), &(), |b, _| {
+            b.iter(|| sum(black_box(&mask)))
+        });
+    }
+    group.finish();
+}
+
+fn groups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("groups");
+
+    for compartment in [12, 48, 256] {
+        let rucksacks = synthetic_groups(3_333, 2 * compartment);
+        let count = |find: &dyn Fn(&[&[Item]]) -> Option<Item>| {
+            rucksacks
+                .chunks_exact(3)
+                .filter(|rucksacks| {
+                    let parts = [&rucksacks[0][..], &rucksacks[1][..], &rucksacks[2][..]];
+                    find(&parts).is_some()
+                })
+                .count()
+        };
+        assert_eq!(count(&scan), count(&mask));
+
+        group.bench_with_input(BenchmarkId::new("scan", compartment), &(), |b, _| {
+            b.iter(|| count(black_box(&scan)))
+        });
+        group.bench_with_input(BenchmarkId::new("mask", compartment), &(), |b, _| {
+            b.iter(|| count(black_box(&mask)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, compartments, groups);
+criterion_main!(benches);
@@ -0,0 +1,365 @@
+mod mask;
+mod priority;
+mod repack;
+mod validate;
+
+use std::{collections::HashSet, num::NonZeroUsize};
+
+use aoc_common::{stream::StreamingSolution, ParseError, Solution};
+use log::{debug, trace, warn};
+
+pub use mask::ItemMask;
+pub use priority::{Item, Preset, PriorityTable};
+pub use repack::{repack, Compartment, Move, Repacking};
+pub use validate::{misplaced_item, Issue, Problem, Validation};
+
+/// How many elves are in a group, as in the puzzle
+pub const DEFAULT_GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+/// How to read rucksacks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub table: PriorityTable,
+    pub group_size: NonZeroUsize,
+    pub validation: Validation,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            table: PriorityTable::default(),
+            group_size: DEFAULT_GROUP_SIZE,
+            validation: Validation::default(),
+        }
+    }
+}
+
+/// The item types in one rucksack, in order. The first half is the first compartment
+pub type Rucksack = Vec<Item>;
+
+/// Every rucksack, with the table their items were looked up in
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    pub table: PriorityTable,
+    pub group_size: NonZeroUsize,
+    pub validation: Validation,
+    pub rucksacks: Vec<Rucksack>,
+}
+
+//Strict validation turns the first bad rucksack into an error, lenient validation only logs it
+fn check_rucksack(
+    table: &PriorityTable,
+    validation: Validation,
+    index: usize,
+    line: &str,
+    rucksack: &[Item],
+) -> Result<Option<Item>, ParseError> {
+    match (misplaced_item(rucksack), validation) {
+        (Ok(item), _) => Ok(Some(item)),
+        (Err(problem), Validation::Strict) => {
+            Err(ParseError::at_line(index, line, problem.describe(table)))
+        }
+        (Err(problem), Validation::Lenient) => {
+            warn!(
+                "Skipping line {} in part one: {}",
+                index + 1,
+                problem.describe(table)
+            );
+            Ok(None)
+        }
+    }
+}
+
+fn incomplete_group(
+    index: usize,
+    line: &str,
+    rucksacks: usize,
+    group_size: NonZeroUsize,
+) -> ParseError {
+    ParseError::at_line(
+        index,
+        line,
+        format!(
+            "Incomplete group, only {rucksacks} of {group_size} rucksacks from here to the end"
+        ),
+    )
+}
+
+fn parse_rucksack(table: &PriorityTable, index: usize, line: &str) -> Result<Rucksack, ParseError> {
+    line.char_indices()
+        .map(|(offset, c)| {
+            table.item(c).ok_or_else(|| {
+                let item = &line[offset..offset + c.len_utf8()];
+                ParseError::at_token(index, line, item, format!("Unknown item type '{c}'"))
+            })
+        })
+        .collect()
+}
+
+/// Parses one rucksack per line. Every character has to be an item type of the table.
+/// Compartments and groups are only checked by [`Inventory::check`], since the reports do
+/// not need them to be well-formed
+pub fn parse_inventory(input: &str, config: Config) -> Result<Inventory, ParseError> {
+    let Config {
+        table,
+        group_size,
+        validation,
+    } = config;
+
+    let rucksacks = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_rucksack(&table, index, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    debug!(
+        "Parsed {} rucksacks with {} item types",
+        rucksacks.len(),
+        table.len()
+    );
+
+    Ok(Inventory {
+        table,
+        group_size,
+        validation,
+        rucksacks,
+    })
+}
+
+impl Inventory {
+    /// With strict validation, fails at the first rucksack without exactly one misplaced item
+    /// type. `input` is the text the inventory was parsed from
+    pub fn check_compartments(&self, input: &str) -> Result<(), ParseError> {
+        for ((index, line), rucksack) in input.lines().enumerate().zip(&self.rucksacks) {
+            check_rucksack(&self.table, self.validation, index, line, rucksack)?;
+        }
+        Ok(())
+    }
+
+    /// Fails if the rucksacks do not split into complete groups
+    pub fn check_groups(&self, input: &str) -> Result<(), ParseError> {
+        let incomplete = self.rucksacks.len() % self.group_size;
+        if incomplete == 0 {
+            return Ok(());
+        }
+
+        let index = self.rucksacks.len() - incomplete;
+        let line = input.lines().nth(index).unwrap_or_default();
+        Err(incomplete_group(index, line, incomplete, self.group_size))
+    }
+
+    /// Everything solving both parts relies on
+    pub fn check(&self, input: &str) -> Result<(), ParseError> {
+        self.check_compartments(input)?;
+        self.check_groups(input)
+    }
+}
+
+//Masks turn each intersection into a single AND, but only tables with up to 64 item types fit
+fn shared_mask<'a>(parts: impl IntoIterator<Item = &'a [Item]>) -> Option<ItemMask> {
+    let mut shared = ItemMask::EMPTY;
+
+    for (i, part) in parts.into_iter().enumerate() {
+        let mask = ItemMask::from_items(part)?;
+        shared = if i == 0 { mask } else { shared & mask };
+    }
+    Some(shared)
+}
+
+fn shared_set<'a>(parts: impl IntoIterator<Item = &'a [Item]>) -> Vec<Item> {
+    let mut parts = parts.into_iter();
+    let Some(first) = parts.next() else {
+        return vec![];
+    };
+
+    let mut shared = first.iter().copied().collect::<HashSet<_>>();
+    for part in parts {
+        let part = part.iter().copied().collect::<HashSet<_>>();
+        shared.retain(|item| part.contains(item));
+    }
+
+    let mut shared = shared.into_iter().collect::<Vec<_>>();
+    shared.sort_unstable();
+    shared
+}
+
+/// The item types found in every one of `parts`, lowest index first
+pub fn shared_items<'a>(parts: impl IntoIterator<Item = &'a [Item]> + Clone) -> Vec<Item> {
+    match shared_mask(parts.clone()) {
+        Some(mask) => mask.iter().collect(),
+        None => shared_set(parts),
+    }
+}
+
+/// The lowest item type found in every one of `parts`
+pub fn first_shared_item<'a>(parts: impl IntoIterator<Item = &'a [Item]> + Clone) -> Option<Item> {
+    match shared_mask(parts.clone()) {
+        Some(mask) => mask.first(),
+        None => shared_set(parts).first().copied(),
+    }
+}
+
+//Groups are meant to share exactly one badge, if there are more this takes the lowest one.
+//`line` is the 1-based line of the group's first rucksack
+fn badge_priority(table: &PriorityTable, line: usize, group: &[Rucksack]) -> Option<u64> {
+    let badges = shared_items(group.iter().map(Vec::as_slice));
+    match badges[..] {
+        [] => warn!("The group at line {line} shares no badge, it is left out of part two"),
+        [_] => {}
+        [first, ..] => warn!(
+            "The group at line {line} shares {} badges, using the lowest one ({})",
+            badges.len(),
+            table.char(first)
+        ),
+    }
+
+    badges.first().map(|&item| table.priority(item).into())
+}
+
+/// One group of elves and the item types all of them carry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// 1-based line number of the group's first rucksack
+    pub line: usize,
+    pub badges: Vec<Item>,
+}
+
+impl Inventory {
+    /// Rucksacks that did not pass validation are left out. Sums are 64-bit, since custom
+    /// tables can have priorities up to `u32::MAX`
+    pub fn misplaced_priorities(&self) -> u64 {
+        self.rucksacks
+            .iter()
+            .flat_map(|rucksack| misplaced_item(rucksack))
+            .inspect(|&item| trace!("{} is in both compartments", self.table.char(item)))
+            .map(|item| u64::from(self.table.priority(item)))
+            .sum::<u64>()
+    }
+
+    /// Every rucksack that does not have exactly one misplaced item type
+    pub fn issues(&self) -> Vec<Issue> {
+        self.rucksacks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rucksack)| {
+                let problem = misplaced_item(rucksack).err()?;
+                Some(Issue {
+                    line: index + 1,
+                    problem,
+                })
+            })
+            .collect()
+    }
+
+    pub fn badge_priorities(&self) -> u64 {
+        let size = self.group_size.get();
+
+        self.rucksacks
+            .chunks_exact(size)
+            .enumerate()
+            .flat_map(|(i, group)| badge_priority(&self.table, i * size + 1, group))
+            .sum::<u64>()
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = Group> + '_ {
+        let size = self.group_size.get();
+
+        self.rucksacks
+            .chunks_exact(size)
+            .enumerate()
+            .map(move |(i, group)| Group {
+                line: i * size + 1,
+                badges: shared_items(group.iter().map(Vec::as_slice)),
+            })
+    }
+
+    /// The groups that do not share exactly one badge
+    pub fn ambiguous_groups(&self) -> Vec<Group> {
+        self.groups()
+            .filter(|group| group.badges.len() != 1)
+            .collect()
+    }
+}
+
+/// Day 3, with the priorities from the puzzle
+pub struct RucksackReorganization;
+
+impl Solution for RucksackReorganization {
+    const DAY: u8 = 3;
+
+    type Parsed = Inventory;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Inventory, ParseError> {
+        let inventory = parse_inventory(input, Config::default())?;
+        inventory.check(input)?;
+
+        Ok(inventory)
+    }
+
+    fn part1(inventory: &Inventory) -> u64 {
+        inventory.misplaced_priorities()
+    }
+
+    fn part2(inventory: &Inventory) -> u64 {
+        inventory.badge_priorities()
+    }
+}
+
+/// The running sums of both parts and the rucksacks of the group that is not complete yet,
+/// along with the line it started on
+#[derive(Debug, Default)]
+pub struct Sums {
+    config: Config,
+    misplaced: u64,
+    badges: u64,
+    group: Vec<Rucksack>,
+    group_start: (usize, String),
+}
+
+impl Sums {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+}
+
+impl StreamingSolution for RucksackReorganization {
+    type State = Sums;
+
+    fn feed(sums: &mut Sums, index: usize, line: &str) -> Result<(), ParseError> {
+        let table = &sums.config.table;
+        let rucksack = parse_rucksack(table, index, line)?;
+
+        if let Some(item) = check_rucksack(table, sums.config.validation, index, line, &rucksack)? {
+            sums.misplaced += u64::from(table.priority(item));
+        }
+
+        if sums.group.is_empty() {
+            sums.group_start = (index, line.to_owned());
+        }
+        sums.group.push(rucksack);
+        if sums.group.len() == sums.config.group_size.get() {
+            let line = sums.group_start.0 + 1;
+            sums.badges += badge_priority(table, line, &sums.group).unwrap_or_default();
+            sums.group.clear();
+        }
+        Ok(())
+    }
+
+    fn finish(sums: Sums) -> Result<(u64, u64), ParseError> {
+        if !sums.group.is_empty() {
+            let (index, line) = &sums.group_start;
+            return Err(incomplete_group(
+                *index,
+                line,
+                sums.group.len(),
+                sums.config.group_size,
+            ));
+        }
+
+        Ok((sums.misplaced, sums.badges))
+    }
+}
@@ -1,55 +1,313 @@
-#![feature(array_chunks)]
-
 use std::{
     error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
+    io::{self, BufWriter, Write},
+    num::NonZeroUsize,
+    path::PathBuf,
+    process::ExitCode,
+    time::Instant,
+};
+
+use aoc_common::{
+    cli::{self, DayArgs, StreamArgs},
+    input::InputSource,
+    output::{self, Format},
+    stream::{feed_stream, StreamingSolution},
+    Answer, Part, Solution,
+};
+use clap::Parser;
+use rucksack_reorganization::{
+    parse_inventory, repack, Compartment, Config, Inventory, Issue, Item, Preset, PriorityTable,
+    Problem, RucksackReorganization, Sums, Validation, DEFAULT_GROUP_SIZE,
 };
+use serde_json::json;
+
+const DEFAULT_INPUT: &str = "./task.txt";
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: DayArgs,
+
+    #[command(flatten)]
+    streaming: StreamArgs,
+
+    /// Use one of the built-in priority tables
+    #[arg(long, value_enum)]
+    preset: Option<Preset>,
+
+    /// Read item priorities from a file with one '<item> <priority>' pair per line
+    #[arg(long, value_name = "FILE", conflicts_with = "preset")]
+    priorities: Option<PathBuf>,
+
+    /// How many elves share a badge. The input has to split into complete groups
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_GROUP_SIZE)]
+    group_size: NonZeroUsize,
+
+    /// List the groups that share no badge or more than one instead of solving both parts
+    #[arg(long, conflicts_with = "stream")]
+    badges: bool,
+
+    /// Suggest the fewest moves that leave no item type in both compartments, per rucksack
+    #[arg(long, conflicts_with_all = ["stream", "badges"])]
+    repack: bool,
+
+    /// List every rucksack that does not have exactly one misplaced item type
+    #[arg(long, conflicts_with_all = ["stream", "badges", "repack"])]
+    validate: bool,
+
+    /// Whether such rucksacks abort with an error or are left out of part one
+    #[arg(long, value_enum, default_value_t)]
+    validation: Validation,
+}
+
+fn load_table(cli: &Cli) -> Result<PriorityTable, Box<dyn Error>> {
+    let Some(path) = &cli.priorities else {
+        return Ok(PriorityTable::preset(cli.preset.unwrap_or_default()));
+    };
+
+    let source = InputSource::from_path(path.clone());
+    Ok(PriorityTable::parse(&source.read()?).map_err(|e| e.with_file(&source))?)
+}
+
+fn config(cli: &Cli) -> Result<Config, Box<dyn Error>> {
+    Ok(Config {
+        table: load_table(cli)?,
+        group_size: cli.group_size,
+        validation: cli.validation,
+    })
+}
+
+fn solve(source: &InputSource, cli: &Cli) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let config = config(cli)?;
 
-trait Priority {
-    fn priority(&self) -> u32;
+    if cli.streaming.stream {
+        let start = Instant::now();
+        let mut sums = Sums::new(config);
+        feed_stream::<RucksackReorganization>(source.open()?, &mut sums)
+            .map_err(|e| e.with_file(source))?;
+        let (misplaced, badges) =
+            RucksackReorganization::finish(sums).map_err(|e| e.with_file(source))?;
+
+        return Ok(vec![
+            cli::answer(Part::One, misplaced, start),
+            cli::answer(Part::Two, badges, start),
+        ]);
+    }
+
+    let input = source.read()?;
+    let inventory = parse_inventory(&input, config)
+        .and_then(|inventory| inventory.check(&input).map(|()| inventory))
+        .map_err(|e| e.with_file(source))?;
+
+    let start = Instant::now();
+    let misplaced = cli::answer(Part::One, inventory.misplaced_priorities(), start);
+    let start = Instant::now();
+    let badges = cli::answer(Part::Two, inventory.badge_priorities(), start);
+
+    Ok(vec![misplaced, badges])
 }
 
-impl Priority for char {
-    fn priority(&self) -> u32 {
-        match self {
-            'a'..='z' => *self as u32 - ('a' as u32) + 1,
-            'A'..='Z' => *self as u32 - ('A' as u32) + 27,
-            _ => panic!("Unexpected item type!"),
+fn print_badges(format: Format, inventory: &Inventory) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    let groups = inventory.ambiguous_groups();
+
+    for group in &groups {
+        let badges = group
+            .badges
+            .iter()
+            .map(|&item| inventory.table.char(item))
+            .collect::<Vec<_>>();
+
+        match format {
+            Format::Text if badges.is_empty() => {
+                writeln!(out, "group at line {}: no shared badge", group.line)?
+            }
+            Format::Text => writeln!(
+                out,
+                "group at line {}: {} shared badges ({})",
+                group.line,
+                badges.len(),
+                badges
+                    .iter()
+                    .map(char::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?,
+            Format::Json => writeln!(out, "{}", json!({ "line": group.line, "badges": badges }))?,
         }
     }
+
+    if format == Format::Text {
+        writeln!(
+            out,
+            "{} of {} groups do not share exactly one badge",
+            groups.len(),
+            inventory.rucksacks.len() / inventory.group_size
+        )?;
+    }
+    out.flush()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = BufReader::new(File::open("./task.txt")?);
+fn print_repackings(format: Format, inventory: &Inventory) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    let table = &inventory.table;
+    let (mut moved, mut repacked, mut stuck) = (0, 0, 0);
 
-    let lines = file.lines().flatten().collect::<Vec<_>>();
+    for (index, rucksack) in inventory.rucksacks.iter().enumerate() {
+        let line = index + 1;
+        let Some(repacking) = repack(rucksack) else {
+            stuck += 1;
+            match format {
+                Format::Text => writeln!(out, "line {line}: cannot be repacked")?,
+                Format::Json => writeln!(out, "{}", json!({ "line": line, "repacked": null }))?,
+            }
+            continue;
+        };
 
-    let priority_sum = lines
-        .iter()
-        .map(|line| {
-            let (first, second) = line.split_at(line.len() / 2);
+        moved += repacking.cost();
+        repacked += !repacking.moves.is_empty() as usize;
 
-            let Some(index) = first.find(|c| {
-            second.contains(c)
-        }) else { panic!() };
+        let (first, second) = repacking.rucksack.split_at(rucksack.len() / 2);
+        let text = |items: &[Item]| {
+            items
+                .iter()
+                .map(|&item| table.char(item))
+                .collect::<String>()
+        };
+        let to = |compartment| match compartment {
+            Compartment::First => "first",
+            Compartment::Second => "second",
+        };
 
-            first.chars().nth(index).unwrap().priority()
-        })
-        .sum::<u32>();
-
-    let group_sum = lines
-        .array_chunks::<3>()
-        .flat_map(|[first, second, third]| {
-            first
-                .chars()
-                .filter(|&c| second.contains(c) && third.contains(c))
-                .nth(0)
-        })
-        .map(|c| c.priority())
-        .sum::<u32>();
+        match format {
+            Format::Text if repacking.moves.is_empty() => {
+                writeln!(out, "line {line}: nothing to move")?
+            }
+            Format::Text => {
+                let moves = repacking
+                    .moves
+                    .iter()
+                    .map(|m| format!("{} {} to the {}", m.count, table.char(m.item), to(m.to)))
+                    .collect::<Vec<_>>();
+                writeln!(
+                    out,
+                    "line {line}: move {} -> {} | {}",
+                    moves.join(", "),
+                    text(first),
+                    text(second)
+                )?
+            }
+            Format::Json => {
+                let moves = repacking
+                    .moves
+                    .iter()
+                    .map(
+                        |m| json!({ "item": table.char(m.item), "count": m.count, "to": to(m.to) }),
+                    )
+                    .collect::<Vec<_>>();
+                writeln!(
+                    out,
+                    "{}",
+                    json!({ "line": line, "moves": moves, "repacked": [text(first), text(second)] })
+                )?
+            }
+        }
+    }
+
+    if format == Format::Text {
+        writeln!(
+            out,
+            "{moved} items moved in {repacked} rucksacks, {stuck} cannot be repacked"
+        )?;
+    }
+    out.flush()
+}
 
-    println!("{priority_sum} {group_sum}");
+fn print_issues(format: Format, inventory: &Inventory) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    let issues = inventory.issues();
 
+    for Issue { line, problem } in &issues {
+        let message = problem.describe(&inventory.table);
+
+        match format {
+            Format::Text => writeln!(out, "line {line}: {message}")?,
+            Format::Json => {
+                let kind = match problem {
+                    Problem::OddItemCount(_) => "odd_item_count",
+                    Problem::NoCommonItem => "no_common_item",
+                    Problem::CommonItems(_) => "common_items",
+                };
+                writeln!(
+                    out,
+                    "{}",
+                    json!({ "line": line, "problem": kind, "message": message })
+                )?
+            }
+        }
+    }
+
+    if format == Format::Text {
+        writeln!(
+            out,
+            "{} of {} rucksacks are malformed",
+            issues.len(),
+            inventory.rucksacks.len()
+        )?;
+    }
+    out.flush()
+}
+
+//Each report only checks what it relies on, so --validate and --repack can list bad rucksacks
+fn report(source: &InputSource, cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let input = source.read()?;
+    let inventory = parse_inventory(&input, config(cli)?).map_err(|e| e.with_file(source))?;
+
+    if cli.badges {
+        inventory
+            .check_groups(&input)
+            .map_err(|e| e.with_file(source))?;
+    }
+
+    if cli.validate {
+        print_issues(cli.args.format, &inventory)?;
+    } else if cli.repack {
+        print_repackings(cli.args.format, &inventory)?;
+    } else {
+        print_badges(cli.args.format, &inventory)?;
+    }
     Ok(())
 }
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let defaults = cli.preset.is_none()
+        && cli.priorities.is_none()
+        && cli.group_size == DEFAULT_GROUP_SIZE
+        && cli.validation == Validation::Strict;
+    let report_mode = cli.badges || cli.repack || cli.validate;
+    if defaults && !report_mode {
+        return cli::run_streaming::<RucksackReorganization>(
+            &cli.args,
+            cli.streaming.stream,
+            DEFAULT_INPUT,
+        );
+    }
+
+    let source = cli.args.init(DEFAULT_INPUT);
+    let result = if report_mode {
+        report(&source, &cli)
+    } else {
+        solve(&source, &cli).map(|answers| {
+            output::print_answers(
+                cli.args.format,
+                RucksackReorganization::DAY,
+                &source,
+                &answers,
+            )
+        })
+    };
+
+    cli::exit_code(result)
+}
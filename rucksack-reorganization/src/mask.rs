@@ -0,0 +1,78 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+use crate::Item;
+
+/// A set of item types as one bit per item. Only holds the first [`ItemMask::CAPACITY`] items of a table
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct ItemMask(pub u64);
+
+impl ItemMask {
+    pub const EMPTY: ItemMask = ItemMask(0);
+    pub const ALL: ItemMask = ItemMask(u64::MAX);
+
+    pub const CAPACITY: usize = u64::BITS as usize;
+
+    /// `None` if any of the items does not fit into a mask
+    pub fn from_items(items: &[Item]) -> Option<Self> {
+        items.iter().try_fold(Self::EMPTY, |mask, &item| {
+            let bit = 1u64.checked_shl(item.0)?;
+            Some(mask | ItemMask(bit))
+        })
+    }
+
+    pub fn contains(self, item: Item) -> bool {
+        1u64.checked_shl(item.0)
+            .is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item with the lowest index
+    pub fn first(self) -> Option<Item> {
+        (!self.is_empty()).then(|| Item(self.0.trailing_zeros()))
+    }
+
+    /// The items, lowest index first
+    pub fn iter(self) -> impl Iterator<Item = Item> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            let item = ItemMask(bits).first()?;
+            bits &= bits - 1;
+            Some(item)
+        })
+    }
+}
+
+impl BitAnd for ItemMask {
+    type Output = ItemMask;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        ItemMask(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for ItemMask {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitOr for ItemMask {
+    type Output = ItemMask;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        ItemMask(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ItemMask {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use aoc_common::ParseError;
+use clap::ValueEnum;
+
+/// An item type, as its position in the [`PriorityTable`] it was looked up in
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Item(pub u32);
+
+/// Built-in priority tables
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Preset {
+    /// a-z are 1-26, A-Z are 27-52
+    #[default]
+    Aoc,
+    /// a-z and A-Z are both 1-26, but still different item types
+    CaseInsensitive,
+    /// Every printable ASCII character from '!' (1) to '~' (94)
+    Ascii,
+}
+
+/// Which characters are item types, and their priorities
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityTable {
+    items: Vec<(char, u32)>,
+    index: HashMap<char, Item>,
+}
+
+impl Default for PriorityTable {
+    fn default() -> Self {
+        Self::preset(Preset::default())
+    }
+}
+
+//`# 3` gives the item `#` a priority, any other line starting with `#` is a comment
+fn is_comment(line: &str) -> bool {
+    let priority = match line.split_once(char::is_whitespace) {
+        Some(("#", priority)) => priority.trim().parse::<u32>().ok(),
+        _ => None,
+    };
+
+    line.starts_with('#') && priority.is_none()
+}
+
+impl PriorityTable {
+    pub fn preset(preset: Preset) -> Self {
+        use Preset::*;
+
+        let items = match preset {
+            Aoc => ('a'..='z').chain('A'..='Z').zip(1..).collect::<Vec<_>>(),
+            CaseInsensitive => ('a'..='z').zip(1..).chain(('A'..='Z').zip(1..)).collect(),
+            Ascii => ('!'..='~').zip(1..).collect(),
+        };
+
+        Self::from_items(items).expect("presets have no duplicate items")
+    }
+
+    /// Builds a table from `(item, priority)` pairs. Fails with the first item listed twice
+    pub fn from_items(items: impl IntoIterator<Item = (char, u32)>) -> Result<Self, char> {
+        let mut table = Self {
+            items: vec![],
+            index: HashMap::new(),
+        };
+
+        for (c, priority) in items {
+            table.insert(c, priority)?;
+        }
+        Ok(table)
+    }
+
+    fn insert(&mut self, c: char, priority: u32) -> Result<(), char> {
+        let item = Item(self.items.len() as u32);
+        if self.index.insert(c, item).is_some() {
+            return Err(c);
+        }
+
+        self.items.push((c, priority));
+        Ok(())
+    }
+
+    /// Parses a table file with one `<item> <priority>` pair per line. Items are single characters,
+    /// empty lines and lines starting with `#` are skipped, unless they give `#` a priority
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut table = Self::from_items([]).unwrap();
+
+        for (index, line) in input.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || is_comment(trimmed) {
+                continue;
+            }
+
+            let Some((item, priority)) = trimmed.split_once(char::is_whitespace) else {
+                return Err(ParseError::at_token(
+                    index,
+                    line,
+                    trimmed,
+                    "Expected '<item> <priority>'",
+                ));
+            };
+
+            let mut chars = item.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                return Err(ParseError::at_token(
+                    index,
+                    line,
+                    item,
+                    "Item types have to be a single character",
+                ));
+            };
+
+            let priority = priority.trim();
+            let priority = priority.parse().map_err(|e| {
+                ParseError::at_token(index, line, priority, format!("Invalid priority: {e}"))
+            })?;
+
+            table.insert(c, priority).map_err(|c| {
+                ParseError::at_token(index, line, item, format!("'{c}' is listed twice"))
+            })?;
+        }
+
+        Ok(table)
+    }
+
+    /// The number of item types
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn item(&self, c: char) -> Option<Item> {
+        self.index.get(&c).copied()
+    }
+
+    pub fn priority(&self, item: Item) -> u32 {
+        self.items[item.0 as usize].1
+    }
+
+    pub fn char(&self, item: Item) -> char {
+        self.items[item.0 as usize].0
+    }
+}
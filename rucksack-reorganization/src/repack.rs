@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use crate::{Item, Rucksack};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+/// Moving `count` items of one type into a compartment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: Item,
+    pub count: usize,
+    pub to: Compartment,
+}
+
+/// The fewest moves that leave no item type in both compartments, and the rucksack after them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repacking {
+    pub moves: Vec<Move>,
+    pub rucksack: Rucksack,
+}
+
+impl Repacking {
+    /// The number of items that change compartments
+    pub fn cost(&self) -> usize {
+        self.moves.iter().map(|m| m.count).sum()
+    }
+}
+
+fn relax(best: &mut Option<usize>, cost: usize) {
+    if best.is_none_or(|best| cost < best) {
+        *best = Some(cost);
+    }
+}
+
+/// Repacks the rucksack so every item type is in only one compartment, while both compartments
+/// keep their size. `None` if the item types cannot be split up like that
+pub fn repack(rucksack: &[Item]) -> Option<Repacking> {
+    let (first, second) = rucksack.split_at(rucksack.len() / 2);
+
+    //How many of each type are in the first and second compartment
+    let mut counts = BTreeMap::<Item, (usize, usize)>::new();
+    for &item in first {
+        counts.entry(item).or_default().0 += 1;
+    }
+    for &item in second {
+        counts.entry(item).or_default().1 += 1;
+    }
+    let types = counts.into_iter().collect::<Vec<_>>();
+
+    //best[i][size] is the fewest moves so that the first i types put `size` items into the
+    //first compartment. Every type goes there completely or not at all
+    let capacity = first.len();
+    let mut best = vec![vec![None; capacity + 1]; types.len() + 1];
+    best[0][0] = Some(0);
+
+    for (i, &(_, (a, b))) in types.iter().enumerate() {
+        for size in 0..=capacity {
+            let Some(cost) = best[i][size] else {
+                continue;
+            };
+
+            relax(&mut best[i + 1][size], cost + a);
+            if size + a + b <= capacity {
+                relax(&mut best[i + 1][size + a + b], cost + b);
+            }
+        }
+    }
+    best[types.len()][capacity]?;
+
+    let mut in_first = BTreeMap::new();
+    let mut moves = vec![];
+    let mut size = capacity;
+    for (i, &(item, (a, b))) in types.iter().enumerate().rev() {
+        let cost = best[i + 1][size];
+        let to_first = size >= a + b && best[i][size - a - b].map(|c| c + b) == cost;
+
+        if to_first {
+            size -= a + b;
+            if b > 0 {
+                moves.push(Move {
+                    item,
+                    count: b,
+                    to: Compartment::First,
+                });
+            }
+        } else if a > 0 {
+            moves.push(Move {
+                item,
+                count: a,
+                to: Compartment::Second,
+            });
+        }
+        in_first.insert(item, to_first);
+    }
+    moves.reverse();
+
+    let (mut repacked, second): (Rucksack, Rucksack) =
+        rucksack.iter().partition(|item| in_first[item]);
+    repacked.extend(second);
+
+    Some(Repacking {
+        moves,
+        rucksack: repacked,
+    })
+}
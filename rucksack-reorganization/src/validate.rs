@@ -0,0 +1,78 @@
+use clap::ValueEnum;
+
+use crate::{shared_mask, shared_set, Item, PriorityTable};
+
+/// What to do with rucksacks that do not have exactly one misplaced item type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Validation {
+    /// Stop at the first one with an error
+    #[default]
+    Strict,
+    /// Leave them out of part one
+    Lenient,
+}
+
+/// Why a rucksack has no single misplaced item type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The items cannot be split into two compartments of the same size
+    OddItemCount(usize),
+    NoCommonItem,
+    /// All item types in both compartments, lowest index first
+    CommonItems(Vec<Item>),
+}
+
+impl Problem {
+    /// Renders the problem with the item characters of `table`
+    pub fn describe(&self, table: &PriorityTable) -> String {
+        use Problem::*;
+
+        match self {
+            OddItemCount(count) => {
+                format!("Odd number of items ({count}), the compartments cannot be the same size")
+            }
+            NoCommonItem => "No item type is in both compartments".to_owned(),
+            CommonItems(items) => {
+                let items = items
+                    .iter()
+                    .map(|&item| table.char(item).to_string())
+                    .collect::<Vec<_>>();
+                format!(
+                    "{} item types are in both compartments: {}",
+                    items.len(),
+                    items.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// A rucksack that failed validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    /// 1-based line number
+    pub line: usize,
+    pub problem: Problem,
+}
+
+/// The one item type that is in both compartments
+pub fn misplaced_item(rucksack: &[Item]) -> Result<Item, Problem> {
+    use Problem::*;
+
+    if !rucksack.len().is_multiple_of(2) {
+        return Err(OddItemCount(rucksack.len()));
+    }
+
+    let (first, second) = rucksack.split_at(rucksack.len() / 2);
+    let shared = match shared_mask([first, second]) {
+        Some(mask) if mask.len() == 1 => return Ok(mask.first().unwrap()),
+        Some(mask) => mask.iter().collect(),
+        None => shared_set([first, second]),
+    };
+
+    match shared[..] {
+        [] => Err(NoCommonItem),
+        [item] => Ok(item),
+        _ => Err(CommonItems(shared)),
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_common::{
+    stream::{feed_stream, StreamError, StreamingSolution},
+    ParseError,
+};
+use rucksack_reorganization::{Config, RucksackReorganization, Sums};
+
+/// Streams `input` through [`Sums`] configured with `config`, keeping parse errors comparable
+pub fn stream(input: &str, config: Config) -> Result<(u64, u64), ParseError> {
+    let mut sums = Sums::new(config);
+    feed_stream::<RucksackReorganization>(input.as_bytes(), &mut sums).map_err(|e| match e {
+        StreamError::Parse(e) => e,
+        e => panic!("{e}"),
+    })?;
+
+    RucksackReorganization::finish(sums)
+}
@@ -0,0 +1,7 @@
+use rucksack_reorganization::RucksackReorganization;
+
+aoc_common::examples! {
+    RucksackReorganization;
+    example: "test.txt" => (Some(157), Some(70)),
+    task: "task.txt" => (Some(8515), Some(2434)),
+}
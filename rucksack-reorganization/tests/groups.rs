@@ -0,0 +1,87 @@
+use std::num::NonZeroUsize;
+
+mod common;
+
+use common::stream;
+
+use aoc_common::{ParseError, Solution};
+use rucksack_reorganization::{parse_inventory, Config, Group, Inventory, RucksackReorganization};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+fn config(group_size: usize) -> Config {
+    Config {
+        group_size: NonZeroUsize::new(group_size).unwrap(),
+        ..Config::default()
+    }
+}
+
+fn inventory(input: &str, group_size: usize) -> Result<Inventory, ParseError> {
+    parse_inventory(input, config(group_size))
+}
+
+#[test]
+fn group_size() {
+    let pairs = inventory(EXAMPLE, 2).unwrap();
+    assert_eq!(pairs.groups().count(), 3);
+    assert_eq!(pairs.misplaced_priorities(), 157);
+
+    let all = inventory(EXAMPLE, 6).unwrap();
+    assert_eq!(
+        all.groups().collect::<Vec<_>>(),
+        [Group {
+            line: 1,
+            badges: vec![]
+        }]
+    );
+    assert_eq!(all.badge_priorities(), 0);
+
+    assert_eq!(stream(EXAMPLE, config(3)), Ok((157, 70)));
+    assert_eq!(stream(EXAMPLE, config(6)), Ok((157, 0)));
+}
+
+#[test]
+fn incomplete_groups_are_errors() {
+    let inventory = inventory(EXAMPLE, 4).unwrap();
+    assert_eq!(inventory.rucksacks.len(), 6);
+
+    for error in [
+        inventory.check_groups(EXAMPLE).unwrap_err(),
+        inventory.check(EXAMPLE).unwrap_err(),
+        stream(EXAMPLE, config(4)).unwrap_err(),
+    ] {
+        assert_eq!((error.line(), error.column()), (5, 1));
+        assert!(error.message().contains("2 of 4"), "{}", error.message());
+    }
+
+    let input = EXAMPLE.lines().take(5).collect::<Vec<_>>().join("\n");
+    assert_eq!(RucksackReorganization::parse(&input).unwrap_err().line(), 4);
+    assert_eq!(stream(&input, config(3)).unwrap_err().line(), 4);
+}
+
+#[test]
+fn ambiguous_groups() {
+    let example = inventory(EXAMPLE, 3).unwrap();
+    assert!(example.ambiguous_groups().is_empty());
+
+    let pairs = inventory(EXAMPLE, 2).unwrap();
+    let groups = pairs.ambiguous_groups();
+    let badges = groups
+        .iter()
+        .map(|group| {
+            let badges = group.badges.iter().map(|&item| pairs.table.char(item));
+            (group.line, badges.collect::<String>())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        badges,
+        [
+            (1, "frsFM".to_owned()),
+            (3, "qvwBT".to_owned()),
+            (5, "GJZ".to_owned())
+        ]
+    );
+    //Ties go to the lowest priority
+    assert_eq!(pairs.badge_priorities(), 6 + 17 + 33);
+}
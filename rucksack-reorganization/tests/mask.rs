@@ -0,0 +1,61 @@
+use rucksack_reorganization::{
+    first_shared_item, parse_inventory, shared_items, Config, Item, ItemMask, Preset, PriorityTable,
+};
+
+#[test]
+fn masks() {
+    let mask = ItemMask::from_items(&[Item(3), Item(0), Item(63), Item(3)]).unwrap();
+    assert_eq!(mask.len(), 3);
+    assert!(mask.contains(Item(63)) && !mask.contains(Item(1)) && !mask.contains(Item(64)));
+    assert_eq!(mask.first(), Some(Item(0)));
+    assert_eq!(
+        mask.iter().collect::<Vec<_>>(),
+        [Item(0), Item(3), Item(63)]
+    );
+
+    let other = ItemMask::from_items(&[Item(3), Item(5)]).unwrap();
+    assert_eq!((mask & other).iter().collect::<Vec<_>>(), [Item(3)]);
+    assert_eq!((mask | other).len(), 4);
+
+    assert_eq!(ItemMask::EMPTY.first(), None);
+    assert_eq!(ItemMask::from_items(&[Item(64)]), None);
+}
+
+#[test]
+fn shared() {
+    let parts: [&[Item]; 3] = [
+        &[Item(1), Item(4), Item(2), Item(4)],
+        &[Item(4), Item(2), Item(9)],
+        &[Item(2), Item(4)],
+    ];
+    assert_eq!(shared_items(parts), [Item(2), Item(4)]);
+    assert_eq!(first_shared_item(parts), Some(Item(2)));
+    assert_eq!(first_shared_item([&parts[0][..1], parts[1]]), None);
+    assert_eq!(shared_items(Vec::<&[Item]>::new()), []);
+}
+
+//The ASCII preset has more item types than fit into a mask, so it takes the slow path
+#[test]
+fn large_tables() {
+    let input = "a~b~\n!}c}\nzz}z";
+    let inventory = parse_inventory(
+        input,
+        Config {
+            table: PriorityTable::preset(Preset::Ascii),
+            ..Config::default()
+        },
+    )
+    .unwrap();
+    let [first, second, third] = &inventory.rucksacks[..] else {
+        panic!()
+    };
+    assert!(ItemMask::from_items(first).is_none());
+
+    let tilde = inventory.table.item('~').unwrap();
+    assert_eq!(shared_items([&first[..2], &first[2..]]), [tilde]);
+    assert_eq!(
+        shared_items([&first[..], second, third]),
+        Vec::<Item>::new()
+    );
+    assert_eq!(inventory.misplaced_priorities(), 94 + 93 + 90);
+}
@@ -0,0 +1,132 @@
+use std::num::NonZeroUsize;
+
+mod common;
+
+use common::stream;
+
+use aoc_common::Solution;
+use rucksack_reorganization::{
+    parse_inventory, Config, Preset, PriorityTable, RucksackReorganization,
+};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn presets() {
+    let aoc = PriorityTable::preset(Preset::Aoc);
+    let priority = |table: &PriorityTable, c| table.priority(table.item(c).unwrap());
+
+    assert_eq!(aoc.len(), 52);
+    assert_eq!(priority(&aoc, 'p'), 16);
+    assert_eq!(priority(&aoc, 'L'), 38);
+    assert_eq!(aoc, PriorityTable::default());
+
+    let insensitive = PriorityTable::preset(Preset::CaseInsensitive);
+    assert_eq!(priority(&insensitive, 'L'), 12);
+    assert_ne!(insensitive.item('l'), insensitive.item('L'));
+
+    let ascii = PriorityTable::preset(Preset::Ascii);
+    assert_eq!(ascii.len(), 94);
+    assert_eq!(priority(&ascii, '!'), 1);
+    assert_eq!(priority(&ascii, '~'), 94);
+}
+
+#[test]
+fn table_files() {
+    let aoc = ('a'..='z')
+        .chain('A'..='Z')
+        .zip(1..)
+        .map(|(c, priority)| format!("{c} {priority}\n"))
+        .collect::<String>();
+    let table = PriorityTable::parse(&format!("# the puzzle's table\n\n{aoc}")).unwrap();
+    assert_eq!(table, PriorityTable::default());
+
+    let inventory = parse_inventory(
+        EXAMPLE,
+        Config {
+            table,
+            ..Config::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(inventory.misplaced_priorities(), 157);
+    assert_eq!(inventory.badge_priorities(), 70);
+}
+
+#[test]
+fn hash_items() {
+    let ascii = ('!'..='~')
+        .zip(1..)
+        .map(|(c, priority)| format!("{c} {priority}\n"))
+        .collect::<String>();
+    let table = PriorityTable::parse(&format!("# printable ASCII\n#\n{ascii}")).unwrap();
+
+    assert_eq!(table, PriorityTable::preset(Preset::Ascii));
+    assert_eq!(table.priority(table.item('#').unwrap()), 3);
+    assert!(PriorityTable::parse("# 3 items\n").unwrap().is_empty());
+}
+
+#[test]
+fn table_file_errors() {
+    let error = |input| {
+        let e = PriorityTable::parse(input).unwrap_err();
+        (e.line(), e.column(), e.message().to_owned())
+    };
+
+    assert_eq!(error("a 1\nb").0, 2);
+    assert_eq!(error("ab 1").1, 1);
+    assert_eq!(error("a x").1, 3);
+
+    let (line, column, message) = error("a 1\nb 2\n  a 3");
+    assert_eq!((line, column), (3, 3));
+    assert!(message.contains("twice"), "{message}");
+}
+
+#[test]
+fn unicode_items() {
+    let table = PriorityTable::parse("ä 1\nö 2\n🎁 10\nx 3").unwrap();
+
+    let inventory = parse_inventory(
+        "äöö🎁\n🎁xä🎁\n",
+        Config {
+            table,
+            group_size: NonZeroUsize::new(2).unwrap(),
+            ..Config::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(inventory.rucksacks[0].len(), 4);
+    assert_eq!(inventory.misplaced_priorities(), 2 + 10);
+}
+
+#[test]
+fn unknown_items_are_errors() {
+    let error = RucksackReorganization::parse("abcb\nabä!ab\n").unwrap_err();
+    assert_eq!((error.line(), error.column()), (2, 3));
+    assert!(error.message().contains("'ä'"), "{}", error.message());
+
+    let table = PriorityTable::preset(Preset::CaseInsensitive);
+    assert!(parse_inventory(
+        "a~a",
+        Config {
+            table,
+            group_size: NonZeroUsize::MIN,
+            ..Config::default()
+        }
+    )
+    .is_err());
+}
+
+#[test]
+fn large_priorities() {
+    let config = || Config {
+        table: PriorityTable::parse("a 4000000000").unwrap(),
+        ..Config::default()
+    };
+    let input = "aa\naa\naa\n";
+
+    let inventory = parse_inventory(input, config()).unwrap();
+    assert_eq!(inventory.misplaced_priorities(), 12_000_000_000);
+    assert_eq!(inventory.badge_priorities(), 4_000_000_000);
+    assert_eq!(stream(input, config()), Ok((12_000_000_000, 4_000_000_000)));
+}
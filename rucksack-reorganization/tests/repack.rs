@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use aoc_common::Solution;
+use rucksack_reorganization::{
+    parse_inventory, repack, Compartment, Config, Item, Move, Rucksack, RucksackReorganization,
+};
+
+fn items(ids: &[u32]) -> Rucksack {
+    ids.iter().map(|&id| Item(id)).collect()
+}
+
+fn is_packed(rucksack: &[Item]) -> bool {
+    let (first, second) = rucksack.split_at(rucksack.len() / 2);
+    let first = first.iter().collect::<HashSet<_>>();
+
+    second.iter().all(|item| !first.contains(item))
+}
+
+//Tries every way of sending whole item types to one side
+fn brute_force(rucksack: &[Item]) -> Option<usize> {
+    let (first, second) = rucksack.split_at(rucksack.len() / 2);
+    let mut types = rucksack.to_vec();
+    types.sort_unstable();
+    types.dedup();
+
+    (0u32..1 << types.len())
+        .filter(|subset| {
+            let size = rucksack
+                .iter()
+                .filter(|item| subset & (1 << types.binary_search(item).unwrap()) != 0)
+                .count();
+            size == first.len()
+        })
+        .map(|subset| {
+            let in_first = |item: &Item| subset & (1 << types.binary_search(item).unwrap()) != 0;
+            first.iter().filter(|item| !in_first(item)).count()
+                + second.iter().filter(|item| in_first(item)).count()
+        })
+        .min()
+}
+
+#[test]
+fn example() {
+    let inventory = RucksackReorganization::parse(include_str!("../test.txt")).unwrap();
+    let table = &inventory.table;
+
+    let repacking = repack(&inventory.rucksacks[0]).unwrap();
+    let item = |c| table.item(c).unwrap();
+    assert_eq!(
+        repacking.moves,
+        [
+            Move {
+                item: item('p'),
+                count: 1,
+                to: Compartment::Second
+            },
+            Move {
+                item: item('s'),
+                count: 1,
+                to: Compartment::First
+            },
+        ]
+    );
+    assert_eq!(repacking.cost(), 2);
+
+    let text = repacking
+        .rucksack
+        .iter()
+        .map(|&item| table.char(item))
+        .collect::<String>();
+    assert_eq!(text, "vJrwWtwJgWrsphcFMMfFFhFp");
+}
+
+#[test]
+fn matches_brute_force() {
+    let mut seed = 0x2022_0322u64;
+    let mut next = |n: u64| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % n) as u32
+    };
+
+    for _ in 0..500 {
+        let len = next(13) as usize;
+        let rucksack = (0..len).map(|_| Item(next(6))).collect::<Vec<_>>();
+
+        let repacking = repack(&rucksack);
+        assert_eq!(
+            repacking.as_ref().map(|r| r.cost()),
+            brute_force(&rucksack),
+            "{rucksack:?}"
+        );
+
+        if let Some(repacking) = repacking {
+            assert!(is_packed(&repacking.rucksack), "{repacking:?}");
+
+            let mut before = rucksack.clone();
+            let mut after = repacking.rucksack.clone();
+            before.sort_unstable();
+            after.sort_unstable();
+            assert_eq!(before, after);
+        }
+    }
+}
+
+#[test]
+fn edge_cases() {
+    assert_eq!(repack(&[]).unwrap().moves, []);
+
+    let packed = items(&[1, 1, 2, 3, 3, 3]);
+    assert_eq!(repack(&packed).unwrap().rucksack, packed);
+
+    //Three of one type can never be split evenly
+    assert_eq!(repack(&items(&[1, 1, 1, 2])), None);
+
+    //Odd lengths keep the larger second compartment
+    let odd = repack(&items(&[1, 2, 2, 3, 1])).unwrap();
+    assert_eq!(odd.cost(), 2);
+    assert!(is_packed(&odd.rucksack));
+}
+
+#[test]
+fn malformed_rucksacks() {
+    //Several shared item types fail strict validation, but repacking does not need it
+    let input = "abca\nabab\n";
+    let inventory = parse_inventory(input, Config::default()).unwrap();
+    assert!(inventory.check_compartments(input).is_err());
+
+    for rucksack in &inventory.rucksacks {
+        let repacking = repack(rucksack).unwrap();
+        assert_eq!(repacking.cost(), 2);
+        assert!(is_packed(&repacking.rucksack));
+    }
+}
@@ -0,0 +1,12 @@
+use aoc_common::testing::check_stream;
+use rucksack_reorganization::RucksackReorganization;
+
+#[test]
+fn example() {
+    check_stream::<RucksackReorganization>(include_str!("../test.txt"));
+}
+
+#[test]
+fn task() {
+    check_stream::<RucksackReorganization>(include_str!("../task.txt"));
+}
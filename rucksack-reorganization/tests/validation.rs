@@ -0,0 +1,118 @@
+mod common;
+
+use common::stream;
+
+use aoc_common::Solution;
+use rucksack_reorganization::{
+    misplaced_item, parse_inventory, Config, Issue, Item, Preset, PriorityTable, Problem,
+    RucksackReorganization, Validation,
+};
+
+const MALFORMED: &str = "abca\nabcabc\nabcd\nabc\nxyzXYZ\naa\n";
+
+fn config(validation: Validation) -> Config {
+    Config {
+        validation,
+        ..Config::default()
+    }
+}
+
+#[test]
+fn problems() {
+    let items = |ids: &[u32]| ids.iter().map(|&id| Item(id)).collect::<Vec<_>>();
+
+    assert_eq!(misplaced_item(&items(&[1, 2, 3, 2])), Ok(Item(2)));
+    assert_eq!(
+        misplaced_item(&items(&[1, 2, 3])),
+        Err(Problem::OddItemCount(3))
+    );
+    assert_eq!(
+        misplaced_item(&items(&[1, 2, 3, 4])),
+        Err(Problem::NoCommonItem)
+    );
+    assert_eq!(
+        misplaced_item(&items(&[1, 2, 2, 1])),
+        Err(Problem::CommonItems(items(&[1, 2])))
+    );
+    //Too many item types for a mask
+    assert_eq!(
+        misplaced_item(&items(&[90, 70, 70, 90])),
+        Err(Problem::CommonItems(items(&[70, 90])))
+    );
+    assert_eq!(misplaced_item(&[]), Err(Problem::NoCommonItem));
+}
+
+#[test]
+fn strict_validation_aborts() {
+    for error in [
+        RucksackReorganization::parse(MALFORMED).unwrap_err(),
+        stream(MALFORMED, config(Validation::Strict)).unwrap_err(),
+    ] {
+        assert_eq!(error.line(), 2);
+        assert!(error.message().contains("a, b, c"), "{}", error.message());
+    }
+
+    let inventory = parse_inventory(MALFORMED, config(Validation::Strict)).unwrap();
+    assert_eq!(
+        inventory.check_compartments(MALFORMED).unwrap_err().line(),
+        2
+    );
+
+    let odd = RucksackReorganization::parse("abcb\nabc\n").unwrap_err();
+    assert_eq!(odd.line(), 2);
+    assert!(odd.message().contains("Odd"), "{}", odd.message());
+}
+
+#[test]
+fn lenient_validation_skips() {
+    let inventory = parse_inventory(MALFORMED, config(Validation::Lenient)).unwrap();
+    assert_eq!(inventory.rucksacks.len(), 6);
+    assert_eq!(inventory.misplaced_priorities(), 2);
+    assert_eq!(
+        stream(MALFORMED, config(Validation::Lenient)).map(|(misplaced, _)| misplaced),
+        Ok(2)
+    );
+
+    let lines = inventory
+        .issues()
+        .into_iter()
+        .map(|Issue { line, problem }| (line, problem))
+        .collect::<Vec<_>>();
+    let [a, b, c] = ['a', 'b', 'c'].map(|c| inventory.table.item(c).unwrap());
+    assert_eq!(
+        lines,
+        [
+            (2, Problem::CommonItems(vec![a, b, c])),
+            (3, Problem::NoCommonItem),
+            (4, Problem::OddItemCount(3)),
+            (5, Problem::NoCommonItem),
+        ]
+    );
+}
+
+#[test]
+fn reports_ignore_incomplete_groups() {
+    //--validate lists every bad rucksack, even when the last group is short
+    let input = "abcabc\nabd\n";
+    let inventory = parse_inventory(input, config(Validation::Strict)).unwrap();
+    assert!(inventory.check_groups(input).is_err());
+    assert_eq!(
+        inventory
+            .issues()
+            .into_iter()
+            .map(|issue| issue.line)
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+}
+
+#[test]
+fn descriptions_use_the_table() {
+    let table = PriorityTable::preset(Preset::Ascii);
+    let items = ['~', '!'].map(|c| table.item(c).unwrap());
+
+    assert_eq!(
+        Problem::CommonItems(items.to_vec()).describe(&table),
+        "2 item types are in both compartments: ~, !"
+    );
+}
@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+
+use aoc_common::{ParseError, Solution};
+use log::debug;
+
+type Crate = char;
+type Stack = VecDeque<Crate>;
+
+#[derive(Debug)]
+pub struct Step {
+    quantity: usize,
+    from: usize,
+    to: usize,
+}
+
+// PART 1: The CrateMover 9000 moves one crate at a time
+fn apply_step_one_by_one(stacks: &mut [Stack], step: &Step) {
+    for _ in 0..step.quantity {
+        let elem = stacks[step.from].pop_front().unwrap();
+        stacks[step.to].push_front(elem);
+    }
+}
+
+// PART 2: The CrateMover 9001 moves all crates at once
+fn apply_step(stacks: &mut [Stack], step: &Step) {
+    let mut from = stacks[step.from].clone();
+    let mut to = stacks[step.to].clone();
+
+    (0..step.quantity)
+        .map(|_| from.pop_front().unwrap())
+        .collect::<Vec<_>>()
+        .iter()
+        .rev()
+        .for_each(|&elem| to.push_front(elem));
+
+    stacks[step.from] = from;
+    stacks[step.to] = to;
+}
+
+fn apply_steps(mut stacks: Vec<Stack>, steps: &[Step], apply: fn(&mut [Stack], &Step)) -> String {
+    steps.iter().for_each(|step| {
+        apply(&mut stacks, step);
+    });
+    stacks
+        .iter_mut()
+        .map(|stack| stack.pop_front().unwrap_or(' '))
+        .collect::<String>()
+}
+
+//Steps are checked against the stack heights so far, which `heights` is updated to.
+//Both cranes move the same number of crates, so the heights do not depend on the part
+fn parse_step(index: usize, line: &str, heights: &mut [usize]) -> Result<Step, ParseError> {
+    let num_columns = heights.len();
+    let tokens = line.split_ascii_whitespace().collect::<Vec<_>>();
+
+    let ["move", quantity, "from", from, "to", to] = tokens[..] else {
+        return Err(ParseError::at_line(
+            index,
+            line,
+            "Expected 'move <quantity> from <stack> to <stack>'",
+        ));
+    };
+
+    let parse_number = |token: &str| {
+        token
+            .parse::<usize>()
+            .map_err(|e| ParseError::at_token(index, line, token, format!("Invalid number: {e}")))
+    };
+    let parse_stack = |token: &str| match parse_number(token)? {
+        stack @ 1.. if stack <= num_columns => Ok(stack - 1),
+        _ => Err(ParseError::at_token(
+            index,
+            line,
+            token,
+            format!("There are only {num_columns} stacks"),
+        )),
+    };
+
+    let step = Step {
+        quantity: parse_number(quantity)?,
+        from: parse_stack(from)?,
+        to: parse_stack(to)?,
+    };
+
+    if step.quantity > heights[step.from] {
+        return Err(ParseError::at_token(
+            index,
+            line,
+            quantity,
+            format!(
+                "Stack {} only has {} crates at this point",
+                step.from + 1,
+                heights[step.from]
+            ),
+        ));
+    }
+    heights[step.from] -= step.quantity;
+    heights[step.to] += step.quantity;
+
+    Ok(step)
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Stack>, Vec<Step>), ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    let drawing = lines
+        .iter()
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    let Some(&&numbers) = drawing.last() else {
+        return Err(ParseError::at_end(
+            input,
+            "Expected a drawing of the stacks",
+        ));
+    };
+
+    let num_columns = numbers.chars().filter(|c| c.is_numeric()).count();
+
+    if num_columns == 0 {
+        return Err(ParseError::at_line(
+            drawing.len() - 1,
+            numbers,
+            "Expected the stack numbers below the drawing",
+        ));
+    }
+
+    let num_rows = drawing.len() - 1;
+
+    let num_chars_per_row = 4 * num_columns - 1;
+
+    debug!(
+        "Parsing {}x{} Image ({} chars per row)",
+        num_columns, num_rows, num_chars_per_row
+    );
+
+    for (index, line) in drawing.iter().enumerate() {
+        if line.len() != num_chars_per_row {
+            return Err(ParseError::at_line(
+                index,
+                line,
+                format!("Expected {num_chars_per_row} characters per row of the drawing"),
+            ));
+        }
+    }
+
+    let stacks = drawing
+        .iter()
+        .take(num_rows)
+        .map(|line| {
+            line.chars()
+                .enumerate()
+                .filter(|(idx, _)| idx % 4 == 1)
+                .map(|(_, c)| if c != ' ' { Some(c) } else { None })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let stacks = (0..num_columns)
+        .map(|i| {
+            (0..num_rows)
+                .flat_map(|j| *stacks.get(j).unwrap().get(i).unwrap())
+                .collect()
+        })
+        .collect::<Vec<Stack>>();
+
+    let mut heights = stacks.iter().map(Stack::len).collect::<Vec<_>>();
+    let steps = lines
+        .iter()
+        .enumerate()
+        .skip(num_rows + 2)
+        .map(|(index, line)| parse_step(index, line, &mut heights))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((stacks, steps))
+}
+
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    const DAY: u8 = 5;
+
+    type Parsed = (Vec<Stack>, Vec<Step>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((stacks, steps): &Self::Parsed) -> String {
+        apply_steps(stacks.clone(), steps, apply_step_one_by_one)
+    }
+
+    fn part2((stacks, steps): &Self::Parsed) -> String {
+        apply_steps(stacks.clone(), steps, apply_step)
+    }
+}
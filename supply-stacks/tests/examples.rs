@@ -0,0 +1,7 @@
+use supply_stacks::SupplyStacks;
+
+aoc_common::examples! {
+    SupplyStacks;
+    example: "test.txt" => (Some("CMZ".into()), Some("MCD".into())),
+    task: "task.txt" => (Some("FWNSHLDNZ".into()), Some("RNRGDNFQG".into())),
+}
@@ -0,0 +1,21 @@
+use aoc_common::Solution;
+use supply_stacks::SupplyStacks;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn rejects_moving_more_crates_than_a_stack_has() {
+    let input = EXAMPLE.replace("move 1 from 2 to 1", "move 9 from 2 to 1");
+    let error = SupplyStacks::parse(&input).unwrap_err();
+
+    assert_eq!((error.line(), error.column()), (6, 6));
+    assert!(
+        error.message().contains("only has 3"),
+        "{}",
+        error.message()
+    );
+
+    //Stack 1 has 2 crates after the first step, so 3 of them can only be moved then
+    let input = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+    assert_eq!(SupplyStacks::parse(&input).unwrap_err().line(), 7);
+}
@@ -0,0 +1,111 @@
+use std::fmt::Write;
+
+use aoc_common::{ParseError, Solution};
+use grid::{Direction, Grid, GridParseError, Point};
+use log::{debug, log_enabled, Level};
+
+fn tree_height(input: char) -> Option<u8> {
+    input.to_digit(10).map(|height| height as u8)
+}
+
+fn is_visible(trees: &Grid<u8>, position: Point) -> bool {
+    let height = trees[position];
+
+    //Trees on the edge have nothing in the way in at least one direction
+    Direction::ALL.into_iter().any(|direction| {
+        trees
+            .ray(position, direction)
+            .all(|(_, &other)| other < height)
+    })
+}
+
+fn viewing_distance(trees: &Grid<u8>, position: Point, direction: Direction) -> usize {
+    let height = trees[position];
+
+    let mut count = 0;
+    for (_, &other) in trees.ray(position, direction) {
+        count += 1;
+
+        if other >= height {
+            break;
+        }
+    }
+    count
+}
+
+fn get_scenic_score(trees: &Grid<u8>, position: Point) -> usize {
+    Direction::ALL
+        .into_iter()
+        .map(|direction| viewing_distance(trees, position, direction))
+        .product()
+}
+
+fn show_visibility(trees: &Grid<u8>, visible: &Grid<bool>) -> String {
+    let mut out = String::new();
+
+    for (heights, visible) in trees.rows().zip(visible.rows()) {
+        for (height, &visible) in heights.iter().zip(visible) {
+            let color = if visible { "32" } else { "31" };
+            write!(out, "\x1b[{}m{}\x1b[0m ", color, height).unwrap();
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+fn to_parse_error(input: &str, error: GridParseError) -> ParseError {
+    use GridParseError::*;
+
+    match error {
+        Empty => ParseError::at_end(input, "Expected at least one row of trees"),
+        InvalidCell { at, .. } => {
+            let index = at.y as usize;
+            let line = input.lines().nth(index).unwrap_or_default();
+
+            let (offset, c) = line.char_indices().nth(at.x as usize).unwrap_or_default();
+            let token = &line[offset..offset + c.len_utf8()];
+            ParseError::at_token(index, line, token, "Expected a tree height (0-9)")
+        }
+        //The visibility checks assume a rectangular forest
+        RaggedRow { row, expected, .. } => ParseError::at_line(
+            row,
+            input.lines().nth(row).unwrap_or_default(),
+            format!("Expected {expected} trees in every row"),
+        ),
+    }
+}
+
+pub struct TreetopTreeHouse;
+
+impl Solution for TreetopTreeHouse {
+    const DAY: u8 = 8;
+
+    type Parsed = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, tree_height).map_err(|e| to_parse_error(input, e))
+    }
+
+    fn part1(trees: &Grid<u8>) -> usize {
+        let visible = Grid::from_fn(trees.width(), trees.height(), |position| {
+            is_visible(trees, position)
+        });
+
+        if log_enabled!(Level::Debug) {
+            debug!("Visible trees:\n{}", show_visibility(trees, &visible));
+        }
+
+        visible.iter().filter(|&&visible| visible).count()
+    }
+
+    fn part2(trees: &Grid<u8>) -> usize {
+        trees
+            .points()
+            .map(|position| get_scenic_score(trees, position))
+            .max()
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,7 @@
+use treetop_tree_house::TreetopTreeHouse;
+
+aoc_common::examples! {
+    TreetopTreeHouse;
+    example: "test.txt" => (Some(21), Some(8)),
+    task: "task.txt" => (Some(1854), Some(527340)),
+}
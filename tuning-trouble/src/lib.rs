@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+use log::{trace, warn};
+
+const PACKET_MARKER_SIZE: usize = 4;
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+fn shift_into<T, const N: usize>(arr: &mut [T; N], item: T) {
+    //Shift everything over
+    arr.rotate_left(1);
+    //Replace the last item (which was the first before rotating)
+    arr[N - 1] = item;
+}
+
+fn is_same<T>(a: *const T, b: *const T) -> bool {
+    std::ptr::eq(a, b)
+}
+
+fn has_only_unique<T: PartialEq, const N: usize>(arr: &[T; N]) -> bool {
+    //TODO: O(n^2) :(
+
+    for elem in arr {
+        for other in arr {
+            if !is_same(elem, other) & (other == elem) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Marker {
+    After(usize),
+    NotFound,
+}
+
+impl Display for Marker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Marker::After(index) => write!(f, "{index}"),
+            Marker::NotFound => write!(f, "no marker found"),
+        }
+    }
+}
+
+fn find_marker<const WINDOW_SIZE: usize>(input: &str) -> Marker {
+    let mut last_chars = ['\0'; WINDOW_SIZE];
+
+    for (index, c) in input.chars().enumerate() {
+        shift_into(&mut last_chars, c);
+
+        if (index >= WINDOW_SIZE) && has_only_unique(&last_chars) {
+            return Marker::After(index + 1);
+        }
+    }
+
+    warn!("No Marker found! Searched {} characters", input.len());
+    Marker::NotFound
+}
+
+pub struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    const DAY: u8 = 6;
+
+    type Parsed = String;
+    type Answer1 = Marker;
+    type Answer2 = Marker;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let input = input.trim();
+
+        trace!("Parsing input '{input}'");
+
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> Marker {
+        find_marker::<PACKET_MARKER_SIZE>(input)
+    }
+
+    fn part2(input: &String) -> Marker {
+        find_marker::<MESSAGE_MARKER_SIZE>(input)
+    }
+}
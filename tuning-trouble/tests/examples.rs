@@ -0,0 +1,10 @@
+use tuning_trouble::{Marker, TuningTrouble};
+
+aoc_common::examples! {
+    TuningTrouble;
+    example1: "test1.txt" => (Some(Marker::After(7)), Some(Marker::After(19))),
+    example2: "test2.txt" => (Some(Marker::After(6)), Some(Marker::After(23))),
+    example3: "test3.txt" => (Some(Marker::After(10)), Some(Marker::After(29))),
+    example4: "test4.txt" => (Some(Marker::After(11)), Some(Marker::After(26))),
+    task: "task.txt" => (Some(Marker::After(1850)), Some(Marker::After(2823))),
+}